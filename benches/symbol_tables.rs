@@ -0,0 +1,87 @@
+//! Compares the symbol tables of the searching module on the same workload of random puts and gets,
+//! and reports the height the trees end up with, since that bounds the cost of a search.
+//! The B-tree is also built with different orders, to show how the size of a page affects the height and the pages a lookup reads.
+//! Run it with `cargo bench --bench symbol_tables`.
+
+use std::{hint::black_box, time::{Duration, Instant}};
+
+use itualgs_rs::randomization::lcg_random::lcg_generate_seeded;
+use itualgs_rs::searching::{
+    BST::BST,
+    avl_tree::AVLTreeST,
+    b_tree::BTree,
+    linear_probing_hash_st::LinearProbingHashST,
+    red_black_bst::RedBlackBST,
+    separate_chaining_hash_st::SeparateChainingHashST,
+    treap::Treap,
+};
+
+const N: usize = 100_000;
+const ROUNDS: usize = 5;
+
+/// Runs the workload a few times and reports the fastest put and get phase, together with the height of a tree
+fn bench<ST>(name: &str, keys: &[u32], new: impl Fn() -> ST, put: impl Fn(&mut ST, u32, usize), get: impl Fn(&ST, &u32) -> bool, height: impl Fn(&ST) -> Option<isize>) {
+    let mut best_put = Duration::MAX;
+    let mut best_get = Duration::MAX;
+    let mut tree_height = None;
+    for _ in 0..ROUNDS {
+        let mut st = new();
+        let start = Instant::now();
+        for (i, key) in keys.iter().enumerate() {
+            put(&mut st, *key, i);
+        }
+        best_put = best_put.min(start.elapsed());
+
+        let start = Instant::now();
+        let mut hits = 0;
+        for key in keys {
+            if get(&st, black_box(key)) {
+                hits += 1;
+            }
+        }
+        best_get = best_get.min(start.elapsed());
+        assert_eq!(hits, keys.len());
+        tree_height = height(&st);
+    }
+    let tree_height = tree_height.map_or("-".to_string(), |h| h.to_string());
+    println!("{:<24} put {:>10.2?} get {:>10.2?} height {:>6}", name, best_put, best_get, tree_height);
+}
+
+/// Builds a B-tree of every order from the keys, and reports its height and the average amount of pages a lookup reads
+fn bench_b_tree_orders(keys: &[u32]) {
+    for m in [3, 4, 8, 16, 64, 256, 1024] {
+        let mut bt = BTree::with_order(m);
+        for (i, key) in keys.iter().enumerate() {
+            bt.put(*key, i);
+        }
+        let start = Instant::now();
+        let mut pages = 0;
+        for key in keys {
+            assert!(bt.get(black_box(key)).is_some());
+            pages += bt.page_accesses();
+        }
+        let elapsed = start.elapsed();
+        println!("BTree of order {:<9} get {:>10.2?} height {:>6} pages per get {:>6.2}", m, elapsed, bt.height(), pages as f64 / keys.len() as f64);
+    }
+}
+
+fn main() {
+    let random = lcg_generate_seeded(N, 42);
+    let sorted: Vec<u32> = (0..N as u32 / 10).collect();
+
+    for (workload, keys) in [("random keys", &random), ("sorted keys", &sorted)] {
+        println!("{} keys, {}", keys.len(), workload);
+        bench("BST", keys, BST::new, |st, k, v| st.put(k, v), |st, k| st.get(k).is_some(), |st| Some(st.height()));
+        bench("RedBlackBST", keys, RedBlackBST::new, |st, k, v| st.put(k, v), |st, k| st.get(k).is_some(), |st| Some(st.height()));
+        bench("AVLTreeST", keys, AVLTreeST::new, |st, k, v| st.put(k, v), |st, k| st.get(k).is_some(), |st| Some(st.height()));
+        // the random keys come from an LcgRandom seeded with 42, and priorities equal to the keys would make the treap a path
+        bench("Treap", keys, || Treap::with_seed(7), |st, k, v| st.put(k, v), |st, k| st.get(k).is_some(), |st| Some(st.height()));
+        bench("BTree", keys, || BTree::with_order(16), |st, k, v| st.put(k, v), |st, k| st.get(k).is_some(), |st| Some(st.height()));
+        bench("SeparateChainingHashST", keys, SeparateChainingHashST::new, |st, k, v| st.put(k, v), |st, k| st.get(k).is_some(), |_| None);
+        bench("LinearProbingHashST", keys, LinearProbingHashST::new, |st, k, v| st.put(k, v), |st, k| st.get(k).is_some(), |_| None);
+        println!();
+    }
+
+    println!("{} keys, random keys", random.len());
+    bench_b_tree_orders(&random);
+}
@@ -0,0 +1,84 @@
+//! Compares the union find implementations of the fundamentals module on the same workload of random unions and connected queries,
+//! and on Kruskal's algorithm, which uses a union find to skip the edges that would close a cycle.
+//! Run it with `cargo bench --bench union_find`.
+
+use std::{hint::black_box, time::{Duration, Instant}};
+
+use itualgs_rs::fundamentals::uf::{QuickFindUF, QuickUnionUF, RankPathCompressionUF, WeightedQuickUnionUF, UF};
+use itualgs_rs::graph::{edge::Edge, edge_weighted_graph::EdgeWeightedGraph, kruskal_mst::KruskalMST};
+use itualgs_rs::randomization::lcg_random::lcg_generate_seeded;
+
+const N: usize = 10_000;
+const OPERATIONS: usize = 50_000;
+const V: usize = 2_000;
+const E: usize = 20_000;
+const ROUNDS: usize = 5;
+
+/// Runs the unions and queries a few times, and reports the fastest run together with the amount of components left
+fn bench_operations<U: UF>(name: &str, ops: &[u32]) -> usize {
+    let mut best = Duration::MAX;
+    let mut count = 0;
+    for _ in 0..ROUNDS {
+        let mut uf = U::new(N);
+        let start = Instant::now();
+        let mut connected = 0;
+        // every third operation is a query, and the others are unions
+        for (i, pair) in ops.chunks(2).enumerate() {
+            let (p, q) = (pair[0] as usize % N, pair[1] as usize % N);
+            if i % 3 == 0 {
+                if uf.connected(black_box(p), black_box(q)) {
+                    connected += 1;
+                }
+            } else {
+                uf.union(p, q);
+            }
+        }
+        best = best.min(start.elapsed());
+        black_box(connected);
+        count = uf.count();
+    }
+    println!("{:<24} unions and finds {:>10.2?} components {:>6}", name, best, count);
+    count
+}
+
+/// Finds a minimum spanning forest with the union find a few times, and reports the fastest run together with the weight
+fn bench_kruskal<U: UF>(name: &str, g: &EdgeWeightedGraph) -> u128 {
+    let mut best = Duration::MAX;
+    let mut weight = 0;
+    for _ in 0..ROUNDS {
+        let g = g.clone();
+        let start = Instant::now();
+        let mst = KruskalMST::with_uf::<U>(black_box(g));
+        best = best.min(start.elapsed());
+        weight = mst.weight;
+    }
+    println!("{:<24} kruskal {:>10.2?} weight {:>10}", name, best, weight);
+    weight
+}
+
+fn main() {
+    let ops = lcg_generate_seeded(2 * OPERATIONS, 37);
+    println!("{} elements, {} operations", N, OPERATIONS);
+    let counts = [
+        bench_operations::<QuickFindUF>("QuickFindUF", &ops),
+        bench_operations::<QuickUnionUF>("QuickUnionUF", &ops),
+        bench_operations::<WeightedQuickUnionUF>("WeightedQuickUnionUF", &ops),
+        bench_operations::<RankPathCompressionUF>("RankPathCompressionUF", &ops),
+    ];
+    assert!(counts.iter().all(|c| *c == counts[0]));
+    println!();
+
+    let rand = lcg_generate_seeded(3 * E, 37);
+    let mut g = EdgeWeightedGraph::new(V);
+    for c in rand.chunks(3) {
+        g.add_edge(Edge::new(c[0] as usize % V, c[1] as usize % V, (c[2] % 1000) as u128));
+    }
+    println!("{} vertices, {} edges", V, E);
+    let weights = [
+        bench_kruskal::<QuickFindUF>("QuickFindUF", &g),
+        bench_kruskal::<QuickUnionUF>("QuickUnionUF", &g),
+        bench_kruskal::<WeightedQuickUnionUF>("WeightedQuickUnionUF", &g),
+        bench_kruskal::<RankPathCompressionUF>("RankPathCompressionUF", &g),
+    ];
+    assert!(weights.iter().all(|w| *w == weights[0]));
+}
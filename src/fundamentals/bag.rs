@@ -39,7 +39,7 @@ impl<T> Bag<T> where T:Clone {
     pub fn new_from_vec(v:&Vec<T>) -> Bag<T> {
         let mut b = Bag{
             elements: LinkedList::new(),
-            size: 0,
+            size: v.len(),
         };
         for item in v {
             b.elements.push_front(item.clone());
@@ -53,6 +53,25 @@ impl<T> Bag<T> where T:Clone {
         self.size += 1;
     }
 
+    /// removes one occurrence of the element from the bag, returns false if it was not present
+    pub fn remove(&mut self, item: &T) -> bool
+        where T:PartialEq
+    {
+        self.remove_where(|e| e == item).is_some()
+    }
+
+    /// removes the first element matching the predicate and returns it
+    pub fn remove_where<F>(&mut self, pred: F) -> Option<T>
+        where F: FnMut(&T) -> bool
+    {
+        let index = self.elements.iter().position(pred)?;
+        let mut tail = self.elements.split_off(index);
+        let removed = tail.pop_front();
+        self.elements.append(&mut tail);
+        self.size -= 1;
+        removed
+    }
+
     /// Returns true if bag is empty
     pub fn is_empty(&mut self) -> bool{
         self.elements.is_empty()
@@ -95,4 +114,17 @@ mod tests {
         to_list.reverse();
         assert_eq!(&to_list,&list);
     }
+
+    #[test]
+    fn test_remove() {
+        let list = vec![1,2,3,2];
+        let mut bag = Bag::new_from_vec(&list);
+
+        assert!(bag.remove(&2));
+        assert!(!bag.remove(&5));
+        assert_eq!(bag.size(), 3);
+        let mut to_list = bag.to_list();
+        to_list.sort();
+        assert_eq!(to_list, vec![1,2,3]);
+    }
 }
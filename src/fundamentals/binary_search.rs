@@ -1,10 +1,19 @@
+use std::cmp::Ordering;
+
 /// The fundamentals binary search represents a generic iterative binary search
 /// it has the index_of method which returns the index of a searched for key.
+/// When the key occurs more than once the index of the first occurrence is returned,
+/// and an empty array simply does not contain the key.
 /// This has a time complexity of *O*(log(n)) and has the recurrence relation:
 /// T(n) = T(n/2) + 1
-/// 
+///
+/// Every search in this module is built on the same loop, which keeps the answer between lo and hi
+/// and halves the distance between them, so no index ever goes below 0 or past the end of the array.
+/// The `_by` variants compare the elements to the key with a comparator, which returns how an element is ordered compared to the key,
+/// and the `_by_key` variants compare a key extracted from every element.
+///
 /// Author: cave
-/// 
+///
 /// # Examples
 /// ```
 /// use itualgs_rs::fundamentals::binary_search;
@@ -12,40 +21,217 @@
 /// let list = vec![0,1,2,3,4,5,6,7,8,9];
 /// let index_of_three = binary_search::index_of(&list, 3);
 /// assert!(index_of_three.unwrap() == 3)
-/// ```  
+/// ```
 pub fn index_of<T>(array: &[T], key: T) -> Option<usize>
-where 
+where
     T: PartialOrd,
 {
-    // Get the middle element, high, and low begin here
-    let mut hi = array.len() - 1;
-    let mut lo = 0;
-    let mut middle = array.len() / 2;
-
-    // Get the value
-    let mut current_value = array.get(middle);
+    first_index_of(array, &key)
+}
 
-    // While we haven't iterated through every element, continue searching
-    while hi >= lo {
-        middle = lo + (hi - lo) / 2;
-        current_value = array.get(middle);
+/// The index of the first element which is not ordered before the key, by the predicate `before`.
+/// The array has to be partitioned, so every element ordered before the key comes first
+fn partition_point<T, F>(array: &[T], mut before: F) -> usize
+where
+    F: FnMut(&T) -> bool,
+{
+    let mut lo = 0;
+    let mut hi = array.len();
 
-        // our current guess was too high
-        if Some(&key) < current_value {
-            hi = middle - 1;
-        }
-        // our current guess was too low 
-        else if Some(&key) > current_value {
+    // the answer is always between lo and hi
+    while lo < hi {
+        let middle = lo + (hi - lo) / 2;
+        if before(&array[middle]) {
             lo = middle + 1;
-        } 
-        // we found the correct value from the key
-        else {
-            return Some(middle);
+        } else {
+            hi = middle;
         }
-        
     }
+    lo
+}
+
+/// The rank of a key in a sorted array is the amount of elements strictly smaller than the key.
+/// When the key is in the array this is its first index, and when it is not this is the index
+/// where it would have to be inserted to keep the array sorted. This is what a symbol table backed
+/// by a sorted array, like `BinarySearchST`, needs. It is *O*(log(n)) and works on an empty array.
+///
+/// # Examples
+/// ```
+/// use itualgs_rs::fundamentals::binary_search;
+///
+/// let list = vec![1,3,3,5];
+/// assert_eq!(binary_search::rank(&list, &3), 1);
+/// assert_eq!(binary_search::rank(&list, &4), 3);
+/// assert_eq!(binary_search::rank(&list, &9), 4);
+/// ```
+pub fn rank<T>(array: &[T], key: &T) -> usize
+where
+    T: PartialOrd,
+{
+    lower_bound(array, key)
+}
+
+/// The lower bound is the index of the first element which is not smaller than the key,
+/// or the length of the array if every element is smaller. It is the same as the rank of the key.
+///
+/// # Examples
+/// ```
+/// use itualgs_rs::fundamentals::binary_search;
+///
+/// let list = vec![1,3,3,5];
+/// assert_eq!(binary_search::lower_bound(&list, &3), 1);
+/// assert_eq!(binary_search::upper_bound(&list, &3), 3);
+/// assert_eq!(binary_search::lower_bound(&Vec::<u8>::new(), &3), 0);
+/// ```
+pub fn lower_bound<T>(array: &[T], key: &T) -> usize
+where
+    T: PartialOrd,
+{
+    partition_point(array, |x| x < key)
+}
+
+/// The upper bound is the index of the first element which is larger than the key,
+/// or the length of the array if no element is larger. The elements equal to the key are between the lower and the upper bound.
+pub fn upper_bound<T>(array: &[T], key: &T) -> usize
+where
+    T: PartialOrd,
+{
+    partition_point(array, |x| x <= key)
+}
+
+/// The index of the first occurrence of the key
+pub fn first_index_of<T>(array: &[T], key: &T) -> Option<usize>
+where
+    T: PartialOrd,
+{
+    let i = lower_bound(array, key);
+    if i < array.len() && array[i] == *key {
+        Some(i)
+    } else {
+        None
+    }
+}
 
-    return None;
+/// The index of the last occurrence of the key
+///
+/// # Examples
+/// ```
+/// use itualgs_rs::fundamentals::binary_search;
+///
+/// let list = vec!["a", "b", "b", "b", "c"];
+/// assert_eq!(binary_search::first_index_of(&list, &"b"), Some(1));
+/// assert_eq!(binary_search::last_index_of(&list, &"b"), Some(3));
+/// assert_eq!(binary_search::last_index_of(&list, &"d"), None);
+/// ```
+pub fn last_index_of<T>(array: &[T], key: &T) -> Option<usize>
+where
+    T: PartialOrd,
+{
+    let i = upper_bound(array, key);
+    if i > 0 && array[i - 1] == *key {
+        Some(i - 1)
+    } else {
+        None
+    }
+}
+
+/// The lower bound, where `compare` returns how an element is ordered compared to the key
+///
+/// # Examples
+/// ```
+/// use itualgs_rs::fundamentals::binary_search;
+///
+/// // sorted by decreasing value
+/// let list = vec![9,7,7,2];
+/// let compare = |x: &i32| 7.cmp(x);
+/// assert_eq!(binary_search::lower_bound_by(&list, compare), 1);
+/// assert_eq!(binary_search::upper_bound_by(&list, compare), 3);
+/// ```
+pub fn lower_bound_by<T, F>(array: &[T], mut compare: F) -> usize
+where
+    F: FnMut(&T) -> Ordering,
+{
+    partition_point(array, |x| compare(x) == Ordering::Less)
+}
+
+/// The upper bound, where `compare` returns how an element is ordered compared to the key
+pub fn upper_bound_by<T, F>(array: &[T], mut compare: F) -> usize
+where
+    F: FnMut(&T) -> Ordering,
+{
+    partition_point(array, |x| compare(x) != Ordering::Greater)
+}
+
+/// The index of the first element equal to the key, where `compare` returns how an element is ordered compared to the key
+pub fn first_index_of_by<T, F>(array: &[T], mut compare: F) -> Option<usize>
+where
+    F: FnMut(&T) -> Ordering,
+{
+    let i = lower_bound_by(array, &mut compare);
+    if i < array.len() && compare(&array[i]) == Ordering::Equal {
+        Some(i)
+    } else {
+        None
+    }
+}
+
+/// The index of the last element equal to the key, where `compare` returns how an element is ordered compared to the key
+pub fn last_index_of_by<T, F>(array: &[T], mut compare: F) -> Option<usize>
+where
+    F: FnMut(&T) -> Ordering,
+{
+    let i = upper_bound_by(array, &mut compare);
+    if i > 0 && compare(&array[i - 1]) == Ordering::Equal {
+        Some(i - 1)
+    } else {
+        None
+    }
+}
+
+/// The lower bound of the key, in an array sorted by the keys `extract` returns
+///
+/// # Examples
+/// ```
+/// use itualgs_rs::fundamentals::binary_search;
+///
+/// let people = vec![("Ferris", 7), ("Jane", 23), ("John", 23), ("Doe", 40)];
+/// assert_eq!(binary_search::lower_bound_by_key(&people, &23, |p| p.1), 1);
+/// assert_eq!(binary_search::last_index_of_by_key(&people, &23, |p| p.1), Some(2));
+/// assert_eq!(binary_search::first_index_of_by_key(&people, &30, |p| p.1), None);
+/// ```
+pub fn lower_bound_by_key<T, B, F>(array: &[T], key: &B, mut extract: F) -> usize
+where
+    B: Ord,
+    F: FnMut(&T) -> B,
+{
+    lower_bound_by(array, |x| extract(x).cmp(key))
+}
+
+/// The upper bound of the key, in an array sorted by the keys `extract` returns
+pub fn upper_bound_by_key<T, B, F>(array: &[T], key: &B, mut extract: F) -> usize
+where
+    B: Ord,
+    F: FnMut(&T) -> B,
+{
+    upper_bound_by(array, |x| extract(x).cmp(key))
+}
+
+/// The index of the first element with the key, in an array sorted by the keys `extract` returns
+pub fn first_index_of_by_key<T, B, F>(array: &[T], key: &B, mut extract: F) -> Option<usize>
+where
+    B: Ord,
+    F: FnMut(&T) -> B,
+{
+    first_index_of_by(array, |x| extract(x).cmp(key))
+}
+
+/// The index of the last element with the key, in an array sorted by the keys `extract` returns
+pub fn last_index_of_by_key<T, B, F>(array: &[T], key: &B, mut extract: F) -> Option<usize>
+where
+    B: Ord,
+    F: FnMut(&T) -> B,
+{
+    last_index_of_by(array, |x| extract(x).cmp(key))
 }
 
 #[cfg(test)]
@@ -78,4 +264,70 @@ mod tests {
         let var = index_of(&list, "abcdefgh");
         assert!(var.unwrap() == 5)
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_rank() {
+        use super::rank;
+
+        let list = vec![0,3,5,5,5,9];
+        for key in 0..11 {
+            assert_eq!(rank(&list, &key), list.iter().filter(|x| **x < key).count());
+        }
+        assert_eq!(rank(&Vec::<i32>::new(), &3), 0);
+    }
+
+    #[test]
+    fn test_empty_and_single() {
+        use super::{first_index_of, last_index_of, lower_bound, upper_bound};
+
+        let empty: Vec<i32> = Vec::new();
+        assert_eq!(index_of(&empty, 3), None);
+        assert_eq!(last_index_of(&empty, &3), None);
+        assert_eq!(upper_bound(&empty, &3), 0);
+
+        let single = vec![5];
+        // smaller than every element, which used to underflow
+        assert_eq!(index_of(&single, 1), None);
+        assert_eq!(index_of(&single, 5), Some(0));
+        assert_eq!(index_of(&single, 9), None);
+        assert_eq!(first_index_of(&single, &5), Some(0));
+        assert_eq!(last_index_of(&single, &5), Some(0));
+        assert_eq!((lower_bound(&single, &5), upper_bound(&single, &5)), (0, 1));
+        assert_eq!((lower_bound(&single, &9), upper_bound(&single, &1)), (1, 0));
+    }
+
+    #[test]
+    fn test_same_as_linear_scan() {
+        use super::*;
+        use crate::randomization::lcg_random::lcg_generate_seeded;
+
+        for size in 0..40 {
+            let mut list: Vec<u32> = lcg_generate_seeded(size, 47 + size as u64).into_iter().map(|x| x % 10).collect();
+            list.sort();
+            for key in 0..11 {
+                let first = list.iter().position(|x| *x == key);
+                let last = list.iter().rposition(|x| *x == key);
+                let below = list.iter().filter(|x| **x < key).count();
+                let not_above = list.iter().filter(|x| **x <= key).count();
+
+                assert_eq!(index_of(&list, key), first);
+                assert_eq!(first_index_of(&list, &key), first);
+                assert_eq!(last_index_of(&list, &key), last);
+                assert_eq!(lower_bound(&list, &key), below);
+                assert_eq!(upper_bound(&list, &key), not_above);
+
+                assert_eq!(first_index_of_by(&list, |x| x.cmp(&key)), first);
+                assert_eq!(last_index_of_by(&list, |x| x.cmp(&key)), last);
+                assert_eq!(lower_bound_by(&list, |x| x.cmp(&key)), below);
+                assert_eq!(upper_bound_by(&list, |x| x.cmp(&key)), not_above);
+
+                // the pairs are sorted by their first element
+                let pairs: Vec<(u32, usize)> = list.iter().enumerate().map(|(i, x)| (*x, i)).collect();
+                assert_eq!(first_index_of_by_key(&pairs, &key, |p| p.0), first);
+                assert_eq!(last_index_of_by_key(&pairs, &key, |p| p.0), last);
+                assert_eq!(lower_bound_by_key(&pairs, &key, |p| p.0), below);
+                assert_eq!(upper_bound_by_key(&pairs, &key, |p| p.0), not_above);
+            }
+        }
+    }
+}
@@ -2,4 +2,5 @@ pub mod stack;
 pub mod queue;
 pub mod bag;
 pub mod binary_search;
-pub mod uf;
\ No newline at end of file
+pub mod uf;
+pub mod percolation;
\ No newline at end of file
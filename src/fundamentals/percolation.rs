@@ -0,0 +1,256 @@
+use crate::randomization::lcg_random::LcgRandom;
+
+use super::uf::WeightedQuickUnionUF;
+
+/// The Percolation fundamentals module models an n by n grid of sites, which are either open or blocked.
+/// A site is full if it is open and can be reached from an open site in the top row through a chain of
+/// neighbouring open sites, and the system percolates if a site in the bottom row is full.
+/// The rows and columns are numbered from 0.
+///
+/// The sites are elements of a `WeightedQuickUnionUF` together with a virtual top site connected to the top row
+/// and a virtual bottom site connected to the bottom row, so the system percolates when the two virtual sites are connected.
+/// A second union find without the virtual bottom site answers `is_full`, since with only the first one a site connected
+/// to the bottom row would look full as soon as the system percolates, which is called backwash.
+/// Every operation takes *O(log N)* time, where *N* is the amount of sites.
+///
+/// For additional documentation, see Section 1.5 of Algorithms, 4th Edition by Robert Sedgewick and Kevin Wayne.
+///
+/// # Examples
+/// ```
+/// use itualgs_rs::fundamentals::percolation::Percolation;
+///
+/// let mut perc = Percolation::new(3);
+/// perc.open(0, 1);
+/// perc.open(1, 1);
+/// assert!(perc.is_full(1, 1));
+/// assert!(!perc.percolates());
+///
+/// perc.open(2, 1);
+/// assert!(perc.percolates());
+/// assert_eq!(perc.number_of_open_sites(), 3);
+/// ```
+pub struct Percolation {
+    n: usize,
+    open: Vec<bool>,
+    open_count: usize,
+    // with both the virtual top and bottom site
+    percolation: WeightedQuickUnionUF,
+    // with only the virtual top site, so it has no backwash
+    fullness: WeightedQuickUnionUF,
+}
+
+impl Percolation {
+    /// Create a new n by n grid where every site is blocked
+    pub fn new(n: usize) -> Percolation {
+        if n == 0 {
+            panic!("The grid has to have at least one site!")
+        }
+        Percolation {
+            n,
+            open: vec![false; n * n],
+            open_count: 0,
+            percolation: WeightedQuickUnionUF::new(n * n + 2),
+            fullness: WeightedQuickUnionUF::new(n * n + 1),
+        }
+    }
+
+    /// Opens the site at the given row and column, if it is not open already
+    pub fn open(&mut self, row: usize, col: usize) {
+        let site = self.index(row, col);
+        if self.open[site] {
+            return;
+        }
+        self.open[site] = true;
+        self.open_count += 1;
+
+        if row == 0 {
+            self.percolation.union(site, self.top());
+            self.fullness.union(site, self.top());
+        }
+        if row == self.n - 1 {
+            self.percolation.union(site, self.bottom());
+        }
+
+        let mut neighbours = Vec::with_capacity(4);
+        if row > 0 { neighbours.push((row - 1, col)); }
+        if row + 1 < self.n { neighbours.push((row + 1, col)); }
+        if col > 0 { neighbours.push((row, col - 1)); }
+        if col + 1 < self.n { neighbours.push((row, col + 1)); }
+        for (r, c) in neighbours {
+            let other = r * self.n + c;
+            if self.open[other] {
+                self.percolation.union(site, other);
+                self.fullness.union(site, other);
+            }
+        }
+    }
+
+    /// Checks if the site at the given row and column is open
+    pub fn is_open(&self, row: usize, col: usize) -> bool {
+        self.open[self.index(row, col)]
+    }
+
+    /// Checks if the site at the given row and column is connected to the top row
+    pub fn is_full(&self, row: usize, col: usize) -> bool {
+        let site = self.index(row, col);
+        self.open[site] && self.fullness.connected(site, self.top())
+    }
+
+    /// Returns the amount of open sites
+    pub fn number_of_open_sites(&self) -> usize {
+        self.open_count
+    }
+
+    /// Checks if the top row is connected to the bottom row
+    pub fn percolates(&self) -> bool {
+        self.percolation.connected(self.top(), self.bottom())
+    }
+
+    fn top(&self) -> usize {
+        self.n * self.n
+    }
+
+    fn bottom(&self) -> usize {
+        self.n * self.n + 1
+    }
+
+    fn index(&self, row: usize, col: usize) -> usize {
+        if row >= self.n || col >= self.n {
+            panic!("The index is out of bounds!")
+        }
+        row * self.n + col
+    }
+}
+
+/// The Percolation Stats fundamentals module estimates the percolation threshold, which is the share of
+/// sites that have to be open before an n by n grid percolates. Every trial opens random blocked sites of a
+/// `Percolation` until it percolates, and the share of open sites is recorded.
+///
+/// The sites are drawn from an `LcgRandom`, which can be seeded to make the estimate reproducible.
+/// A trial takes *O(N log N)* time, where *N* is the amount of sites. With a single trial the standard deviation is not defined, and is NaN.
+///
+/// For additional documentation, see Section 1.5 of Algorithms, 4th Edition by Robert Sedgewick and Kevin Wayne.
+///
+/// # Examples
+/// ```
+/// use itualgs_rs::fundamentals::percolation::PercolationStats;
+///
+/// let stats = PercolationStats::with_seed(20, 50, 42);
+/// assert!(stats.confidence_lo() < stats.mean() && stats.mean() < stats.confidence_hi());
+/// assert!((stats.mean() - 0.593).abs() < 0.05);
+/// ```
+pub struct PercolationStats {
+    thresholds: Vec<f64>,
+}
+
+impl PercolationStats {
+    /// Runs the given amount of trials on an n by n grid, seeded from the current time
+    pub fn new(n: usize, trials: usize) -> PercolationStats {
+        PercolationStats::run(n, trials, &mut LcgRandom::new())
+    }
+
+    /// Runs the given amount of trials on an n by n grid, with a fixed seed
+    pub fn with_seed(n: usize, trials: usize, seed: u64) -> PercolationStats {
+        PercolationStats::run(n, trials, &mut LcgRandom::with_seed(seed))
+    }
+
+    fn run(n: usize, trials: usize, random: &mut LcgRandom) -> PercolationStats {
+        if n == 0 || trials == 0 {
+            panic!("There has to be at least one site and one trial!")
+        }
+        let mut thresholds = Vec::with_capacity(trials);
+        for _ in 0..trials {
+            let mut perc = Percolation::new(n);
+            while !perc.percolates() {
+                let (row, col) = (random.uniform(n), random.uniform(n));
+                perc.open(row, col);
+            }
+            thresholds.push(perc.number_of_open_sites() as f64 / (n * n) as f64);
+        }
+        PercolationStats { thresholds }
+    }
+
+    /// Returns the sample mean of the percolation threshold
+    pub fn mean(&self) -> f64 {
+        self.thresholds.iter().sum::<f64>() / self.thresholds.len() as f64
+    }
+
+    /// Returns the sample standard deviation of the percolation threshold
+    pub fn stddev(&self) -> f64 {
+        let mean = self.mean();
+        let squares: f64 = self.thresholds.iter().map(|x| (x - mean) * (x - mean)).sum();
+        (squares / (self.thresholds.len() as f64 - 1.0)).sqrt()
+    }
+
+    /// Returns the low endpoint of the 95% confidence interval
+    pub fn confidence_lo(&self) -> f64 {
+        self.mean() - self.margin()
+    }
+
+    /// Returns the high endpoint of the 95% confidence interval
+    pub fn confidence_hi(&self) -> f64 {
+        self.mean() + self.margin()
+    }
+
+    fn margin(&self) -> f64 {
+        1.96 * self.stddev() / (self.thresholds.len() as f64).sqrt()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Percolation, PercolationStats};
+
+    #[test]
+    pub fn no_backwash() {
+        let mut perc = Percolation::new(3);
+        perc.open(0, 0);
+        perc.open(1, 0);
+        perc.open(2, 0);
+        perc.open(2, 2);
+        assert!(perc.percolates());
+        assert!(perc.is_full(2, 0));
+        assert!(perc.is_open(2, 2));
+        assert!(!perc.is_full(2, 2));
+    }
+
+    #[test]
+    pub fn single_site() {
+        let mut perc = Percolation::new(1);
+        assert!(!perc.percolates());
+        assert!(!perc.is_full(0, 0));
+        perc.open(0, 0);
+        perc.open(0, 0);
+        assert!(perc.percolates());
+        assert_eq!(perc.number_of_open_sites(), 1);
+    }
+
+    #[test]
+    pub fn zig_zag() {
+        let mut perc = Percolation::new(4);
+        for (row, col) in [(0, 3), (1, 3), (1, 2), (1, 1), (2, 1), (3, 0)] {
+            perc.open(row, col);
+            assert!(!perc.percolates());
+        }
+        assert!(!perc.is_full(3, 0));
+        perc.open(2, 0);
+        assert!(perc.is_full(3, 0));
+        assert!(perc.percolates());
+    }
+
+    #[test]
+    #[should_panic]
+    pub fn out_of_bounds() {
+        Percolation::new(2).open(2, 0);
+    }
+
+    #[test]
+    pub fn stats_are_reproducible() {
+        let a = PercolationStats::with_seed(10, 30, 7);
+        let b = PercolationStats::with_seed(10, 30, 7);
+        assert_eq!(a.mean(), b.mean());
+        assert!(a.stddev() > 0.0);
+        assert!(a.confidence_lo() < a.confidence_hi());
+        assert!(PercolationStats::with_seed(5, 1, 7).stddev().is_nan());
+    }
+}
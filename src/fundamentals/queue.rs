@@ -78,6 +78,11 @@ impl<T> Queue<T> {
     pub fn size(&self) -> usize {
         self.elements.len()
     }
+
+    /// Gets an iterator from the front to the back of the queue, without removing the elements.
+    pub fn iterator(&self) -> std::collections::linked_list::Iter<'_, T> {
+        self.elements.iter()
+    }
 } 
 
 impl<T> Default for Queue<T> {
@@ -135,4 +140,15 @@ mod tests {
 
         assert_eq!(queue.is_empty(), false);
     }
+
+    #[test]
+    fn test_iterator() {
+        let mut queue: Queue<u8> = Queue::new();
+        queue.enqueue(20);
+        queue.enqueue(30);
+
+        let elements: Vec<&u8> = queue.iterator().collect();
+        assert_eq!(elements, vec![&20, &30]);
+        assert_eq!(queue.size(), 2);
+    }
 }
@@ -1,3 +1,40 @@
+/// The UF trait is the union find API shared by all the union find structures, so an algorithm
+/// like Kruskals can be written once and run with any of them.
+/// `find` and `connected` take `&mut self`, since a structure with path compression changes its trees when it looks up a root.
+///
+/// # Examples
+/// ```
+/// use itualgs_rs::fundamentals::uf::{UF, QuickFindUF, RankPathCompressionUF};
+///
+/// fn components<U: UF>(n: usize, pairs: &[(usize, usize)]) -> usize {
+///     let mut uf = U::new(n);
+///     for (p, q) in pairs {
+///         uf.union(*p, *q);
+///     }
+///     uf.count()
+/// }
+///
+/// let pairs = [(0, 1), (2, 3), (1, 0)];
+/// assert_eq!(components::<QuickFindUF>(5, &pairs), 3);
+/// assert_eq!(components::<RankPathCompressionUF>(5, &pairs), 3);
+/// ```
+pub trait UF {
+    /// Create a new union find with n elements were all elements are singletons
+    fn new(n: usize) -> Self;
+
+    /// Finds the root of a node
+    fn find(&mut self, p: usize) -> usize;
+
+    /// Checks if two nodes are connected
+    fn connected(&mut self, p: usize, q: usize) -> bool;
+
+    /// Unions two elements together
+    fn union(&mut self, p: usize, q: usize);
+
+    /// Returns the amount of components
+    fn count(&self) -> usize;
+}
+
 /// The Quick Union fundamentals module represents a union find structure
 /// which allows for checking dynamic connectivity. This specific implementation uses
 /// a Non-Weighted Quick Union without path compression.
@@ -137,6 +174,11 @@ impl WeightedQuickUnionUF {
         self.count
     }
 
+    /// Returns the amount of elements in the component of p
+    pub fn size(&self, p: usize) -> usize {
+        self.size[self.find(p)]
+    }
+
     /// Unions two elements together
     pub fn union(&mut self, p: usize, q: usize) {
         let root_p = self.find(p);
@@ -241,6 +283,319 @@ impl QuickFindUF {
     }
 }
 
+/// The Rank Path Compression fundamentals module represents a union find structure
+/// which allows for checking dynamic connectivity. This specific implementation uses
+/// union by rank together with path compression.
+///
+/// Union by rank links the root of the tree with the smaller rank below the other root, where the rank is
+/// an upper bound on the height of the tree. Path compression makes every node on the path to the root point
+/// directly to the root when find is called, so the trees become almost flat. Together they make every
+/// operation take amortized *O(α(N))* time, where *α* is the inverse Ackermann function, which is below 5 for any practical N.
+///
+/// For additional documentation, see Section 1.5 of Algorithms, 4th Edition by Robert Sedgewick and Kevin Wayne.
+///
+/// # Examples
+/// ```
+/// use itualgs_rs::fundamentals::uf::RankPathCompressionUF;
+///
+/// let mut our_uf = RankPathCompressionUF::new(5);
+/// our_uf.union(0, 2);
+/// our_uf.union(2, 4);
+///
+/// assert!(our_uf.connected(0, 4));
+/// assert!(!our_uf.connected(0, 1));
+/// assert_eq!(our_uf.count(), 3);
+/// ```
+pub struct RankPathCompressionUF {
+    count: usize,
+    rank: Vec<u8>,
+    id: Vec<usize>
+}
+
+impl RankPathCompressionUF {
+    /// Create a new RankPathCompressionUF with N elements were all elements are singletons
+    pub fn new(n: usize) -> RankPathCompressionUF {
+        RankPathCompressionUF { count: n, rank: vec![0; n], id: (0..n).collect() }
+    }
+
+    /// Finds the root of a node, and points every node on the way directly to the root
+    pub fn find(&mut self, p: usize) -> usize {
+        let mut root = p;
+        while root != self.id[root] {
+            root = self.id[root];
+        }
+        let mut p = p;
+        while p != root {
+            let next = self.id[p];
+            self.id[p] = root;
+            p = next;
+        }
+        root
+    }
+
+    /// Checks if two nodes are connected by comparing their roots
+    pub fn connected(&mut self, p: usize, q: usize) -> bool {
+        self.find(p) == self.find(q)
+    }
+
+    /// Returns the count
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Unions two elements together
+    pub fn union(&mut self, p: usize, q: usize) {
+        let root_p = self.find(p);
+        let root_q = self.find(q);
+
+        if root_p == root_q {
+            return;
+        }
+
+        // The root with the smaller rank is linked below the other,
+        // only a tie makes the tree higher
+        match self.rank[root_p].cmp(&self.rank[root_q]) {
+            std::cmp::Ordering::Less => self.id[root_p] = root_q,
+            std::cmp::Ordering::Greater => self.id[root_q] = root_p,
+            std::cmp::Ordering::Equal => {
+                self.id[root_q] = root_p;
+                self.rank[root_p] += 1;
+            }
+        }
+        self.count -= 1;
+    }
+}
+
+/// The Rollback fundamentals module represents a union find structure which can undo its unions.
+/// `snapshot` returns a marker of the current state, and `rollback` undoes every union made after the marker,
+/// which is what offline algorithms like dynamic connectivity and backtracking searches need.
+///
+/// It uses a Weighted Quick Union without path compression, since path compression changes
+/// the trees in ways that can not be undone cheaply. So find and union take *O(log N)* time, and undoing a union is constant time.
+///
+/// Every component also keeps an aggregate of the values of its elements, like a sum or a minimum,
+/// which is combined with the `combine` function when two components are unioned and restored on a rollback.
+/// `new` creates a union find without values, where only the size of the components is tracked.
+///
+/// # Examples
+/// ```
+/// use itualgs_rs::fundamentals::uf::RollbackUF;
+///
+/// let mut uf = RollbackUF::with_values(vec![5, 3, 8, 1], |a, b| a + b);
+/// uf.union(0, 1);
+/// let snapshot = uf.snapshot();
+/// uf.union(1, 2);
+/// assert_eq!(*uf.aggregate(0), 16);
+/// assert_eq!(uf.size(2), 3);
+///
+/// uf.rollback(snapshot);
+/// assert_eq!(*uf.aggregate(0), 8);
+/// assert!(!uf.connected(0, 2));
+/// ```
+pub struct RollbackUF<T = ()> {
+    count: usize,
+    size: Vec<usize>,
+    id: Vec<usize>,
+    aggregate: Vec<T>,
+    combine: fn(&T, &T) -> T,
+    // the root linked below another root and the aggregate that root had before, newest last
+    history: Vec<(usize, T)>,
+}
+
+impl RollbackUF {
+    /// Create a new RollbackUF with N elements were all elements are singletons
+    pub fn new(n: usize) -> RollbackUF {
+        RollbackUF::with_values(vec![(); n], |_, _| ())
+    }
+}
+
+impl<T> RollbackUF<T> {
+    /// Create a new RollbackUF with an element for every value, where the aggregates are combined with `combine`
+    pub fn with_values(values: Vec<T>, combine: fn(&T, &T) -> T) -> RollbackUF<T> {
+        let n = values.len();
+        RollbackUF { count: n, size: vec![1; n], id: (0..n).collect(), aggregate: values, combine, history: Vec::new() }
+    }
+
+    /// Finds the root of a node
+    pub fn find(&self, mut p: usize) -> usize {
+        while p != self.id[p] {
+            p = self.id[p];
+        }
+        p
+    }
+
+    /// Checks if two nodes are connected by comparing their roots
+    pub fn connected(&self, p: usize, q: usize) -> bool {
+        self.find(p) == self.find(q)
+    }
+
+    /// Returns the count
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the amount of elements in the component of p
+    pub fn size(&self, p: usize) -> usize {
+        self.size[self.find(p)]
+    }
+
+    /// Returns the aggregate of the values in the component of p
+    pub fn aggregate(&self, p: usize) -> &T {
+        &self.aggregate[self.find(p)]
+    }
+
+    /// Unions two elements together, a union of two connected elements changes nothing and is not recorded
+    pub fn union(&mut self, p: usize, q: usize) {
+        let mut smallest = self.find(p);
+        let mut largest = self.find(q);
+
+        if smallest == largest {
+            return;
+        }
+        if self.size[smallest] > self.size[largest] {
+            std::mem::swap(&mut smallest, &mut largest);
+        }
+
+        let combined = (self.combine)(&self.aggregate[largest], &self.aggregate[smallest]);
+        let old = std::mem::replace(&mut self.aggregate[largest], combined);
+        self.history.push((smallest, old));
+
+        self.id[smallest] = largest;
+        self.size[largest] += self.size[smallest];
+        self.count -= 1;
+    }
+
+    /// Returns a marker of the current state, which can be rolled back to
+    pub fn snapshot(&self) -> usize {
+        self.history.len()
+    }
+
+    /// Undoes every union made since the snapshot was taken
+    pub fn rollback(&mut self, snapshot: usize) {
+        if snapshot > self.history.len() {
+            panic!("The snapshot is newer than the current state!")
+        }
+        while self.history.len() > snapshot {
+            let (smallest, old) = self.history.pop().unwrap();
+            let largest = self.id[smallest];
+            self.aggregate[largest] = old;
+            self.size[largest] -= self.size[smallest];
+            self.id[smallest] = smallest;
+            self.count += 1;
+        }
+    }
+}
+
+impl UF for QuickUnionUF {
+    fn new(n: usize) -> Self {
+        QuickUnionUF::new(n)
+    }
+
+    fn find(&mut self, p: usize) -> usize {
+        QuickUnionUF::find(self, p)
+    }
+
+    fn connected(&mut self, p: usize, q: usize) -> bool {
+        QuickUnionUF::connected(self, p, q)
+    }
+
+    fn union(&mut self, p: usize, q: usize) {
+        QuickUnionUF::union(self, p, q)
+    }
+
+    fn count(&self) -> usize {
+        QuickUnionUF::count(self)
+    }
+}
+
+impl UF for WeightedQuickUnionUF {
+    fn new(n: usize) -> Self {
+        WeightedQuickUnionUF::new(n)
+    }
+
+    fn find(&mut self, p: usize) -> usize {
+        WeightedQuickUnionUF::find(self, p)
+    }
+
+    fn connected(&mut self, p: usize, q: usize) -> bool {
+        WeightedQuickUnionUF::connected(self, p, q)
+    }
+
+    fn union(&mut self, p: usize, q: usize) {
+        WeightedQuickUnionUF::union(self, p, q)
+    }
+
+    fn count(&self) -> usize {
+        WeightedQuickUnionUF::count(self)
+    }
+}
+
+impl UF for QuickFindUF {
+    fn new(n: usize) -> Self {
+        QuickFindUF::new(n)
+    }
+
+    fn find(&mut self, p: usize) -> usize {
+        QuickFindUF::find(self, p)
+    }
+
+    fn connected(&mut self, p: usize, q: usize) -> bool {
+        QuickFindUF::connected(self, p, q)
+    }
+
+    fn union(&mut self, p: usize, q: usize) {
+        QuickFindUF::union(self, p, q)
+    }
+
+    fn count(&self) -> usize {
+        QuickFindUF::count(self)
+    }
+}
+
+impl UF for RankPathCompressionUF {
+    fn new(n: usize) -> Self {
+        RankPathCompressionUF::new(n)
+    }
+
+    fn find(&mut self, p: usize) -> usize {
+        RankPathCompressionUF::find(self, p)
+    }
+
+    fn connected(&mut self, p: usize, q: usize) -> bool {
+        RankPathCompressionUF::connected(self, p, q)
+    }
+
+    fn union(&mut self, p: usize, q: usize) {
+        RankPathCompressionUF::union(self, p, q)
+    }
+
+    fn count(&self) -> usize {
+        RankPathCompressionUF::count(self)
+    }
+}
+
+impl UF for RollbackUF {
+    fn new(n: usize) -> Self {
+        RollbackUF::new(n)
+    }
+
+    fn find(&mut self, p: usize) -> usize {
+        RollbackUF::find(self, p)
+    }
+
+    fn connected(&mut self, p: usize, q: usize) -> bool {
+        RollbackUF::connected(self, p, q)
+    }
+
+    fn union(&mut self, p: usize, q: usize) {
+        RollbackUF::union(self, p, q)
+    }
+
+    fn count(&self) -> usize {
+        RollbackUF::count(self)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     /* Tests for QuickFindUF */
@@ -327,4 +682,117 @@ mod tests {
         assert!(qu.connected(7, 2) == true);
         assert!(qu.connected(6, 6) == true);
     }
-}
\ No newline at end of file
+
+    /* Tests for Rank Path Compression */
+    use super::RankPathCompressionUF;
+
+    #[test]
+    pub fn tiny_uf_sedgewick_rpc() {
+        let mut qu = RankPathCompressionUF::new(10);
+        qu.union(4, 3);
+        qu.union(3, 8);
+        qu.union(6, 5);
+        qu.union(9, 4);
+        qu.union(2, 1);
+        qu.union(8, 9);
+        qu.union(5, 0);
+        qu.union(7, 2);
+        qu.union(6, 1);
+        qu.union(1, 0);
+        qu.union(6, 7);
+
+        assert!(qu.connected(1, 6));
+        assert!(qu.connected(7, 2));
+        assert!(qu.connected(6, 6));
+        assert!(!qu.connected(3, 0));
+        assert_eq!(qu.count(), 2);
+    }
+
+    /* Tests for the shared trait */
+    use super::UF;
+    use crate::randomization::lcg_random::lcg_generate_seeded;
+
+    fn run<U: UF>(n: usize, pairs: &[(usize, usize)]) -> (usize, Vec<bool>) {
+        let mut uf = U::new(n);
+        for (p, q) in pairs {
+            uf.union(*p, *q);
+        }
+        let connected = (0..n).flat_map(|p| (0..n).map(move |q| (p, q))).map(|(p, q)| uf.connected(p, q)).collect();
+        (uf.count(), connected)
+    }
+
+    #[test]
+    pub fn all_variants_agree() {
+        let n = 30;
+        let rand = lcg_generate_seeded(40, 37);
+        let pairs: Vec<(usize, usize)> = rand.chunks(2).map(|c| (c[0] as usize % n, c[1] as usize % n)).collect();
+        let expected = run::<QuickFindUF>(n, &pairs);
+        assert_eq!(run::<QuickUnionUF>(n, &pairs), expected);
+        assert_eq!(run::<WeightedQuickUnionUF>(n, &pairs), expected);
+        assert_eq!(run::<RankPathCompressionUF>(n, &pairs), expected);
+        assert_eq!(run::<RollbackUF>(n, &pairs), expected);
+    }
+
+    #[test]
+    pub fn wqu_size() {
+        let mut qu = WeightedQuickUnionUF::new(6);
+        qu.union(0, 1);
+        qu.union(2, 1);
+        qu.union(4, 5);
+        assert_eq!(qu.size(0), 3);
+        assert_eq!(qu.size(5), 2);
+        assert_eq!(qu.size(3), 1);
+    }
+
+    /* Tests for Rollback */
+    use super::RollbackUF;
+
+    #[test]
+    pub fn rollback_restores_state() {
+        let n = 20;
+        let rand = lcg_generate_seeded(60, 38);
+        let pairs: Vec<(usize, usize)> = rand.chunks(2).map(|c| (c[0] as usize % n, c[1] as usize % n)).collect();
+        let mut uf = RollbackUF::with_values((0..n as u32).collect(), |a, b| *a.min(b));
+
+        // the state after every union, which has to come back after a rollback
+        let mut states = Vec::new();
+        for (p, q) in &pairs {
+            let state: Vec<(usize, usize, u32)> = (0..n).map(|v| (uf.find(v), uf.size(v), *uf.aggregate(v))).collect();
+            states.push((uf.snapshot(), uf.count(), state));
+            uf.union(*p, *q);
+        }
+        while let Some((snapshot, count, state)) = states.pop() {
+            uf.rollback(snapshot);
+            assert_eq!(uf.count(), count);
+            for (v, (root, size, min)) in state.into_iter().enumerate() {
+                assert_eq!(uf.find(v), root);
+                assert_eq!(uf.size(v), size);
+                assert_eq!(*uf.aggregate(v), min);
+            }
+        }
+        assert_eq!(uf.count(), n);
+    }
+
+    #[test]
+    pub fn rollback_aggregates() {
+        let mut uf = RollbackUF::with_values(vec![4, 7, 1, 9], |a, b| a + b);
+        uf.union(0, 1);
+        uf.union(2, 3);
+        assert_eq!(*uf.aggregate(1), 11);
+        let snapshot = uf.snapshot();
+        uf.union(3, 0);
+        uf.union(1, 2);
+        assert_eq!(*uf.aggregate(2), 21);
+        assert_eq!(uf.size(0), 4);
+        uf.rollback(snapshot);
+        assert_eq!(*uf.aggregate(3), 10);
+        assert_eq!(uf.count(), 2);
+    }
+
+    #[test]
+    #[should_panic]
+    pub fn rollback_to_the_future() {
+        let mut uf = RollbackUF::new(3);
+        uf.rollback(1);
+    }
+}
@@ -0,0 +1,268 @@
+use crate::{sorting::index_min_pq::IndexMinPQ, fundamentals::stack::Stack};
+
+use super::edge_weighted_graph::EdgeWeightedGraph;
+
+
+/// An implicit graph does not store its edges, instead the neighbours of a vertex are computed when they are asked for.
+/// The vertices are still numbered from 0 to `get_v() - 1`, so they can be used as indices in an `IndexMinPQ`.
+pub trait ImplicitGraph {
+    /// The amount of vertices in the graph
+    fn get_v(&self) -> usize;
+
+    /// The vertices adjacent to v, together with the weight of the edge going there
+    fn neighbors(&self, v: usize) -> Vec<(usize, u128)>;
+}
+
+impl ImplicitGraph for EdgeWeightedGraph {
+    fn get_v(&self) -> usize {
+        self.V
+    }
+
+    fn neighbors(&self, v: usize) -> Vec<(usize, u128)> {
+        self.adj(&v).map(|e| (*e.other(v), e.weight)).collect()
+    }
+}
+
+
+/// A grid is a 2D implicit graph, where every open cell is connected to the open cells above, below, left and right of it.
+/// Every step has the weight 1. The cell (x, y) is the vertex `y * width + x`.
+pub struct Grid {
+    width: usize,
+    height: usize,
+    blocked: Vec<bool>,
+}
+
+impl Grid {
+
+    /// Inits a new grid without any obstacles
+    pub fn new(width: usize, height: usize) -> Self {
+        Grid { width, height, blocked: vec![false; width * height] }
+    }
+
+    /// Places an obstacle at (x, y)
+    pub fn block(&mut self, x: usize, y: usize){
+        let v = self.index(x, y);
+        self.blocked[v] = true;
+    }
+
+    /// Checks if there is an obstacle at (x, y)
+    pub fn is_blocked(&self, x: usize, y: usize) -> bool {
+        self.blocked[self.index(x, y)]
+    }
+
+    /// The vertex of the cell (x, y)
+    pub fn index(&self, x: usize, y: usize) -> usize {
+        if x >= self.width || y >= self.height {
+            panic!("The cell is out of bounds!")
+        }
+        y * self.width + x
+    }
+
+    /// The cell (x, y) of the vertex v
+    pub fn coords(&self, v: usize) -> (usize, usize) {
+        (v % self.width, v / self.width)
+    }
+
+    /// The manhattan distance between v and w, which is an admissible heuristic on the grid
+    pub fn manhattan(&self, v: usize, w: usize) -> u128 {
+        let (x1, y1) = self.coords(v);
+        let (x2, y2) = self.coords(w);
+        (x1.abs_diff(x2) + y1.abs_diff(y2)) as u128
+    }
+}
+
+impl ImplicitGraph for Grid {
+    fn get_v(&self) -> usize {
+        self.width * self.height
+    }
+
+    fn neighbors(&self, v: usize) -> Vec<(usize, u128)> {
+        if self.blocked[v] {
+            return Vec::new();
+        }
+        let mut res: Vec<usize> = Vec::with_capacity(4);
+        let (x, y) = self.coords(v);
+        if x > 0 {
+            res.push(v - 1);
+        }
+        if x + 1 < self.width {
+            res.push(v + 1);
+        }
+        if y > 0 {
+            res.push(v - self.width);
+        }
+        if y + 1 < self.height {
+            res.push(v + self.width);
+        }
+        res.into_iter().filter(|w| !self.blocked[*w]).map(|w| (w, 1)).collect()
+    }
+}
+
+
+/// A* search finds the shortest path between two vertices s and t. It works like Dijkstras algorithm,
+/// but the vertices are taken from the priority queue ordered by their distance from s plus the heuristic estimate of the distance to t.
+///
+/// The heuristic has to be admissible, which means that it may never overestimate the distance to t, otherwise the path might not be the shortest.
+/// With a heuristic that always returns 0, A* expands the same vertices as Dijkstras algorithm.
+///
+/// The worst case running time is the same as Dijkstra, *O(E log V)*, but a good heuristic makes A* expand far fewer vertices.
+/// The amount of expanded vertices is available through `expanded`.
+///
+/// # Examples
+/// ```
+/// use itualgs_rs::graph::a_star::{AStar, Grid};
+///
+/// let mut grid = Grid::new(4, 3);
+/// grid.block(1, 0);
+/// grid.block(1, 1);
+///
+/// let s = grid.index(0, 0);
+/// let t = grid.index(3, 0);
+/// let a_star = AStar::new(&grid, s, t, |v| grid.manhattan(v, t));
+/// assert_eq!(a_star.get_distance(), 7);
+///
+/// let mut path = a_star.path().unwrap();
+/// assert_eq!(path.size(), 8);
+/// assert_eq!(path.pop().unwrap(), s);
+/// ```
+pub struct AStar {
+    target: usize,
+    dist_to: Vec<u128>,
+    edge_to: Vec<Option<usize>>,
+    expanded: usize,
+}
+
+impl AStar {
+
+    /// Runs A* from s to t, with the heuristic h giving an estimate of the distance from a vertex to t
+    pub fn new<G, H>(g: &G, s: usize, t: usize, h: H) -> Self
+        where G: ImplicitGraph, H: Fn(usize) -> u128
+    {
+        let v = g.get_v();
+        if s >= v || t >= v {
+            panic!("Index out of bounds")
+        }
+        let mut a_star = AStar { target: t, dist_to: vec![u128::MAX; v], edge_to: vec![None; v], expanded: 0 };
+        let mut pq = IndexMinPQ::<u128>::new(v);
+
+        a_star.dist_to[s] = 0;
+        pq.insert(&s, h(s));
+        while !pq.is_empty() {
+            let v = pq.delete_min();
+            a_star.expanded += 1;
+            if v == t {
+                break;
+            }
+            for (w, weight) in g.neighbors(v) {
+                let dist = a_star.dist_to[v].saturating_add(weight);
+                if dist < a_star.dist_to[w] {
+                    a_star.dist_to[w] = dist;
+                    a_star.edge_to[w] = Some(v);
+                    let f = dist.saturating_add(h(w));
+                    if pq.contains(w) {
+                        pq.decrease_key(&w, f);
+                    } else {
+                        pq.insert(&w, f);
+                    }
+                }
+            }
+        }
+        a_star
+    }
+
+    /// is constant time
+    pub fn has_path(&self) -> bool {
+        self.dist_to[self.target] < u128::MAX
+    }
+
+    /// The length of the shortest path, u128::MAX if there is no path
+    pub fn get_distance(&self) -> u128 {
+        self.dist_to[self.target]
+    }
+
+    /// The amount of vertices taken from the priority queue
+    pub fn expanded(&self) -> usize {
+        self.expanded
+    }
+
+    /// The vertices on the path, with s on the top of the stack. Is *O(N)* in the amount of *N* vertices on the path
+    pub fn path(&self) -> Option<Stack<usize>> {
+        if !self.has_path() {
+            return None;
+        }
+        let mut s = Stack::<usize>::new();
+        let mut x = self.target;
+        s.push(x);
+        while let Some(v) = self.edge_to[x] {
+            s.push(v);
+            x = v;
+        }
+        Some(s)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::graph::{dijkstra_sp::DijkstraSP, edge::Edge, edge_weighted_graph::EdgeWeightedGraph};
+
+    use super::{AStar, Grid};
+
+    #[test]
+    fn test_grid_with_wall(){
+        let mut grid = Grid::new(5, 5);
+        for y in 0..4 {
+            grid.block(2, y);
+        }
+        let s = grid.index(0, 0);
+        let t = grid.index(4, 0);
+        let a_star = AStar::new(&grid, s, t, |v| grid.manhattan(v, t));
+        assert_eq!(a_star.get_distance(), 12);
+
+        let mut path = a_star.path().unwrap();
+        let mut prev = path.pop().unwrap();
+        assert_eq!(prev, s);
+        while let Some(v) = path.pop() {
+            assert_eq!(grid.manhattan(prev, v), 1);
+            let (x, y) = grid.coords(v);
+            assert!(!grid.is_blocked(x, y));
+            prev = v;
+        }
+        assert_eq!(prev, t);
+
+        let dijkstra = AStar::new(&grid, s, t, |_| 0);
+        assert_eq!(dijkstra.get_distance(), 12);
+        assert!(a_star.expanded() <= dijkstra.expanded());
+    }
+
+    #[test]
+    fn test_no_path(){
+        let mut grid = Grid::new(3, 3);
+        for x in 0..3 {
+            grid.block(x, 1);
+        }
+        let t = grid.index(2, 2);
+        let a_star = AStar::new(&grid, 0, t, |v| grid.manhattan(v, t));
+        assert!(!a_star.has_path());
+        assert!(a_star.path().is_none());
+        assert_eq!(a_star.expanded(), 3);
+    }
+
+    #[test]
+    fn test_same_as_dijkstra(){
+        let edges = vec![(0, 1, 4), (0, 2, 1), (2, 1, 2), (1, 3, 5), (2, 3, 8), (3, 4, 3), (2, 4, 20)];
+        let build = || {
+            let mut g = EdgeWeightedGraph::new(5);
+            for (v, w, weight) in &edges {
+                g.add_edge(Edge::new(*v, *w, *weight));
+            }
+            g
+        };
+        let g = build();
+        let dijkstra = DijkstraSP::new(build(), 0);
+        for t in 0..5 {
+            let a_star = AStar::new(&g, 0, t, |_| 0);
+            assert_eq!(a_star.get_distance(), dijkstra.get_distance_to(&t));
+        }
+    }
+}
@@ -22,7 +22,7 @@ use std::collections::{linked_list::Iter};
 /// let mut g = Graph::new(4);
 /// g.add_edge(0, 1);
 /// g.add_edge(0, 2);
-/// let paths = BFP::new(&mut g, 2);
+/// let paths = BFP::new(&g, 2);
 /// let mut s = paths.get_path_to(1).unwrap(); 
 /// assert_eq!(s.pop().unwrap(), 2 as usize);
 /// assert_eq!(s.pop().unwrap(), 0 as usize);
@@ -38,7 +38,7 @@ pub struct BFP{
 impl BFP {
 
     /// creates a new marked list from a graph
-    pub fn new(g:&mut Graph, s: usize) -> BFP{
+    pub fn new(g:&Graph, s: usize) -> BFP{
         let tmp = Self::bfs(g,s);
         BFP { marked: tmp.0 ,edge_to:tmp.1 , dist_to: tmp.2}
     }
@@ -85,7 +85,7 @@ impl BFP {
     }
 
     /// Runs bfs on the given graph
-    fn bfs(g:&mut Graph, s: usize) -> (Vec<bool>, Vec<usize>, Vec<usize>){
+    fn bfs(g:&Graph, s: usize) -> (Vec<bool>, Vec<usize>, Vec<usize>){
         let mut m:Vec<bool> = vec![false;g.get_v()];
         let mut edge_to:Vec<usize> = vec![0;g.get_v()];
         let mut dist_to:Vec<usize> = vec![Self::infinity();g.get_v()];
@@ -126,7 +126,7 @@ mod tests {
         let mut g = Graph::new(4);
         g.add_edge(0, 1);
         g.add_edge(0, 2);
-        let paths = BFP::new(&mut g, 2);
+        let paths = BFP::new(&g, 2);
         let mut s = paths.get_path_to(1).unwrap(); 
         assert_eq!(s.pop().unwrap(), 2 as usize);
         assert_eq!(s.pop().unwrap(), 0 as usize);
@@ -0,0 +1,173 @@
+use crate::fundamentals::stack::Stack;
+
+use super::graph::Graph;
+
+
+/// Bidirectional breath first paths finds a shortest path between two vertices s and t in an undirected unweighted graph.
+/// Instead of building the full shortest path tree from s like `BFP`, it runs a breath first search from both s and t,
+/// one level at a time from the side with the smallest frontier, and stops as soon as the two searches meet.
+///
+/// The worst case running time is still *O(V+E)*, but when s and t are close the two searches touch far fewer vertices.
+/// The distance and path are the same as the ones `BFP::new(g, s)` gives for t.
+///
+/// # Examples
+/// ```
+/// use itualgs_rs::graph::bidirectional_bfp::BidirectionalBFP;
+/// use itualgs_rs::graph::graph::Graph;
+///
+/// let mut g = Graph::new(5);
+/// g.add_edge(0, 1);
+/// g.add_edge(1, 2);
+/// g.add_edge(2, 3);
+/// g.add_edge(0, 4);
+/// let paths = BidirectionalBFP::new(&g, 0, 3);
+/// assert_eq!(paths.get_dist(), 3);
+/// let mut s = paths.get_path().unwrap();
+/// assert_eq!(s.pop().unwrap(), 0);
+/// assert_eq!(s.pop().unwrap(), 1);
+/// assert_eq!(s.pop().unwrap(), 2);
+/// assert_eq!(s.pop().unwrap(), 3);
+/// ```
+pub struct BidirectionalBFP {
+    dist: usize,
+    path: Vec<usize>,
+}
+
+impl BidirectionalBFP {
+
+    /// searches for a shortest path between s and t
+    pub fn new(g:&Graph, s: usize, t: usize) -> BidirectionalBFP {
+        if s >= g.get_v() || t >= g.get_v() {
+            panic!("Index out of bounds")
+        }
+        if s == t {
+            return BidirectionalBFP { dist: 0, path: vec![s] };
+        }
+
+        let mut dist_to = [vec![Self::infinity(); g.get_v()], vec![Self::infinity(); g.get_v()]];
+        let mut edge_to = [vec![0; g.get_v()], vec![0; g.get_v()]];
+        let mut frontier = [vec![s], vec![t]];
+        dist_to[0][s] = 0;
+        dist_to[1][t] = 0;
+
+        let mut meet: Option<usize> = None;
+        while meet.is_none() && !frontier[0].is_empty() && !frontier[1].is_empty() {
+            let side = if frontier[0].len() <= frontier[1].len() { 0 } else { 1 };
+            let other = 1 - side;
+            let mut next = Vec::new();
+            let mut best = Self::infinity();
+
+            for v in &frontier[side] {
+                for w in g.adj_vertices(v) {
+                    if dist_to[side][*w] == Self::infinity() {
+                        dist_to[side][*w] = dist_to[side][*v] + 1;
+                        edge_to[side][*w] = *v;
+                        next.push(*w);
+                    }
+                    if dist_to[other][*w] != Self::infinity() && dist_to[0][*w] + dist_to[1][*w] < best {
+                        best = dist_to[0][*w] + dist_to[1][*w];
+                        meet = Some(*w);
+                    }
+                }
+            }
+            frontier[side] = next;
+        }
+
+        match meet {
+            None => BidirectionalBFP { dist: Self::infinity(), path: Vec::new() },
+            Some(m) => {
+                let mut path = Vec::new();
+                let mut x = m;
+                while x != s {
+                    path.push(x);
+                    x = edge_to[0][x];
+                }
+                path.push(s);
+                path.reverse();
+                x = m;
+                while x != t {
+                    x = edge_to[1][x];
+                    path.push(x);
+                }
+                BidirectionalBFP { dist: path.len() - 1, path }
+            }
+        }
+    }
+
+    fn infinity() -> usize {
+        usize::MAX
+    }
+
+    /// Is there a path between s and t
+    pub fn has_path(&self) -> bool {
+        !self.path.is_empty()
+    }
+
+    /// Get the amount of edges on a shortest path between s and t, usize::MAX if there is none
+    pub fn get_dist(&self) -> usize {
+        self.dist
+    }
+
+    /// Gets the vertices on the path, with s on the top of the stack
+    pub fn get_path(&self) -> Option<Stack<usize>> {
+        if !self.has_path() {
+            return None;
+        }
+        let mut stack = Stack::<usize>::new();
+        for v in self.path.iter().rev() {
+            stack.push(*v);
+        }
+        Some(stack)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::graph::{bfp::BFP, graph::Graph};
+    use crate::randomization::lcg_random::lcg_generate_seeded;
+
+    use super::BidirectionalBFP;
+
+    #[test]
+    fn test_no_path(){
+        let mut g = Graph::new(4);
+        g.add_edge(0, 1);
+        g.add_edge(2, 3);
+        let paths = BidirectionalBFP::new(&g, 0, 3);
+        assert!(!paths.has_path());
+        assert!(paths.get_path().is_none());
+    }
+
+    #[test]
+    fn test_same_as_bfp(){
+        let v = 30;
+        let rand = lcg_generate_seeded(80, 28);
+        let mut g = Graph::new(v);
+        for i in (0..rand.len()).step_by(2) {
+            g.add_edge(rand[i] as usize % v, rand[i+1] as usize % v);
+        }
+
+        for s in 0..v {
+            let bfp = BFP::new(&g, s);
+            for t in 0..v {
+                let paths = BidirectionalBFP::new(&g, s, t);
+                assert_eq!(paths.has_path(), bfp.has_path_to(t));
+                if !paths.has_path() {
+                    continue;
+                }
+                assert_eq!(paths.get_dist(), bfp.get_dist_to(t));
+
+                let mut path = paths.get_path().unwrap();
+                assert_eq!(path.size(), bfp.get_dist_to(t) + 1);
+                let mut prev = path.pop().unwrap();
+                assert_eq!(prev, s);
+                while let Some(w) = path.pop() {
+                    assert!(g.has_edge(prev, w));
+                    prev = w;
+                }
+                assert_eq!(prev, t);
+            }
+        }
+    }
+}
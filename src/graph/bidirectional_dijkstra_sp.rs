@@ -0,0 +1,214 @@
+use crate::{sorting::index_min_pq::IndexMinPQ, fundamentals::stack::Stack};
+
+use super::{edge::Edge, edge_weighted_graph::EdgeWeightedGraph};
+
+
+/// Bidirectional Dijkstra finds the shortest path between two vertices s and t in an undirected edge weighted graph.
+/// It runs Dijkstras algorithm from s and from t at the same time, always advancing the side with the smallest key,
+/// and stops when the sum of the two smallest keys is at least as long as the best path found where the searches meet.
+///
+/// The worst case running time is the same as `DijkstraSP`, *O(E log V)*, but only the vertices closer than about half the distance are touched.
+/// The distance and path are the same as the ones `DijkstraSP::new(g, s)` gives for t.
+///
+/// # Examples
+/// ```
+/// use itualgs_rs::graph::bidirectional_dijkstra_sp::BidirectionalDijkstraSP;
+/// use itualgs_rs::graph::edge::Edge;
+/// use itualgs_rs::graph::edge_weighted_graph::EdgeWeightedGraph;
+///
+/// let mut g = EdgeWeightedGraph::new(4);
+/// let list = vec![
+///     Edge::new(0, 1, 10),
+///     Edge::new(2, 1, 2),
+///     Edge::new(2, 0, 20)];
+///
+/// for ele in list {
+///     g.add_edge(ele);
+/// }
+/// let sp = BidirectionalDijkstraSP::new(&g, 0, 2);
+/// assert_eq!(sp.get_distance(), 12);
+///
+/// let mut path = sp.path().unwrap();
+/// assert_eq!(path.pop().unwrap().weight, 10);
+/// assert_eq!(path.pop().unwrap().weight, 2);
+/// assert_eq!(path.is_empty(), true);
+/// ```
+pub struct BidirectionalDijkstraSP {
+    dist: u128,
+    path: Option<Vec<Edge>>,
+}
+
+struct Search {
+    dist_to: Vec<u128>,
+    edge_to: Vec<Option<Edge>>,
+    pq: IndexMinPQ<u128>,
+}
+
+impl Search {
+    fn new(v: usize, s: usize) -> Self {
+        let mut search = Search { dist_to: vec![u128::MAX; v], edge_to: vec![None; v], pq: IndexMinPQ::new(v) };
+        search.dist_to[s] = 0;
+        search.pq.insert(&s, 0);
+        search
+    }
+
+    /// the edges from the root of the search to v, with the root first
+    fn path_to(&self, v: usize) -> Vec<Edge> {
+        let mut path = Vec::new();
+        let mut x = v;
+        while let Some(e) = &self.edge_to[x] {
+            path.push(e.clone());
+            x = *e.other(x);
+        }
+        path.reverse();
+        path
+    }
+}
+
+impl BidirectionalDijkstraSP {
+
+    /// searches for a shortest path between s and t
+    pub fn new(g: &EdgeWeightedGraph, s: usize, t: usize) -> Self {
+        if s >= g.V || t >= g.V {
+            panic!("Index out of bounds")
+        }
+        if s == t {
+            return BidirectionalDijkstraSP { dist: 0, path: Some(Vec::new()) };
+        }
+        let mut searches = [Search::new(g.V, s), Search::new(g.V, t)];
+
+        // the best path found so far goes from s to meet.0, over the edge meet.2 and from meet.1 to t
+        let mut best = u128::MAX;
+        let mut meet: Option<(usize, usize, Edge)> = None;
+
+        while !searches[0].pq.is_empty() && !searches[1].pq.is_empty() {
+            let min_f = searches[0].pq.min_key().unwrap();
+            let min_b = searches[1].pq.min_key().unwrap();
+            if min_f.saturating_add(min_b) >= best {
+                break;
+            }
+            let side = if min_f <= min_b { 0 } else { 1 };
+            let other = 1 - side;
+
+            let v = searches[side].pq.delete_min();
+            for e in g.adj(&v) {
+                let w = *e.other(v);
+                Self::relax(&mut searches[side], e, v);
+
+                let reach = searches[other].dist_to[w];
+                if reach == u128::MAX {
+                    continue;
+                }
+                let dist = searches[side].dist_to[v] + e.weight + reach;
+                if dist < best {
+                    best = dist;
+                    meet = Some(if side == 0 { (v, w, (**e).clone()) } else { (w, v, (**e).clone()) });
+                }
+            }
+        }
+
+        match meet {
+            None => BidirectionalDijkstraSP { dist: u128::MAX, path: None },
+            Some((a, b, e)) => {
+                let mut path = searches[0].path_to(a);
+                path.push(e);
+                let mut back = searches[1].path_to(b);
+                back.reverse();
+                path.append(&mut back);
+                BidirectionalDijkstraSP { dist: best, path: Some(path) }
+            }
+        }
+    }
+
+    fn relax(search: &mut Search, e: &Edge, v: usize){
+        let w = *e.other(v);
+        let dist = search.dist_to[v] + e.weight;
+        if search.dist_to[w] > dist {
+            search.dist_to[w] = dist;
+            search.edge_to[w] = Some(e.clone());
+            if search.pq.contains(w) {
+                search.pq.decrease_key(&w, dist);
+            } else {
+                search.pq.insert(&w, dist);
+            }
+        }
+    }
+
+    /// is constant time
+    pub fn get_distance(&self) -> u128 {
+        self.dist
+    }
+
+    /// is constant time
+    pub fn has_path(&self) -> bool {
+        self.path.is_some()
+    }
+
+    /// The edges on the path, with the edge leaving s on the top of the stack
+    pub fn path(&self) -> Option<Stack<Edge>> {
+        let path = self.path.as_ref()?;
+        let mut s = Stack::<Edge>::new();
+        for e in path.iter().rev() {
+            s.push(e.clone());
+        }
+        Some(s)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::graph::{dijkstra_sp::DijkstraSP, edge::Edge, edge_weighted_graph::EdgeWeightedGraph};
+    use crate::randomization::lcg_random::lcg_generate_seeded;
+
+    use super::BidirectionalDijkstraSP;
+
+    fn build(v: usize, edges: &[(usize, usize, u128)]) -> EdgeWeightedGraph {
+        let mut g = EdgeWeightedGraph::new(v);
+        for (a, b, weight) in edges {
+            g.add_edge(Edge::new(*a, *b, *weight));
+        }
+        g
+    }
+
+    #[test]
+    fn test_no_path(){
+        let g = build(4, &[(0, 1, 3), (2, 3, 4)]);
+        let sp = BidirectionalDijkstraSP::new(&g, 0, 3);
+        assert!(!sp.has_path());
+        assert_eq!(sp.get_distance(), u128::MAX);
+        assert!(sp.path().is_none());
+    }
+
+    #[test]
+    fn test_same_as_dijkstra(){
+        let v = 25;
+        let rand = lcg_generate_seeded(180, 28);
+        let edges: Vec<(usize, usize, u128)> = rand.chunks(3)
+            .map(|c| (c[0] as usize % v, c[1] as usize % v, (c[2] % 50) as u128))
+            .collect();
+        let g = build(v, &edges);
+
+        for s in 0..v {
+            let dijkstra = DijkstraSP::new(build(v, &edges), s);
+            for t in 0..v {
+                let sp = BidirectionalDijkstraSP::new(&g, s, t);
+                assert_eq!(sp.has_path(), dijkstra.has_path_to(&t));
+                if !sp.has_path() {
+                    continue;
+                }
+                assert_eq!(sp.get_distance(), dijkstra.get_distance_to(&t));
+
+                let mut path = sp.path().unwrap();
+                let mut x = s;
+                let mut total = 0;
+                while let Some(e) = path.pop() {
+                    x = *e.other(x);
+                    total += e.weight;
+                }
+                assert_eq!(x, t);
+                assert_eq!(total, sp.get_distance());
+            }
+        }
+    }
+}
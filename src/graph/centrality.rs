@@ -0,0 +1,283 @@
+use super::{bfp::BFP, digraph::Digraph, directed_bfp::DirectedBFP, graph::Graph};
+
+
+/// Centrality metrics rank the vertices of a graph or a digraph by how important they are in the network.
+///
+/// * `degree_centrality` is the degree of a vertex divided by *V-1*. In a digraph both the edges in and out are counted.
+/// * `closeness_centrality` is based on the average distance from a vertex to the vertices it can reach, found by `BFP` or `DirectedBFP`.
+///   It is scaled by the share of the graph that can be reached, so a vertex in a small component is not the most central. It is *O(V(V+E))*.
+/// * `betweenness_centrality` is the amount of shortest paths between other vertices that go through a vertex, computed by Brandes algorithm on top of the breath first search.
+///   A pair of vertices connected by several shortest paths counts each of them with an equal share. In a graph every pair is counted once. It is *O(V(V+E))*.
+/// * `page_rank` is the share of the time a random surfer spends on a vertex, when it follows a random edge with probability `damping` and jumps to a random vertex otherwise.
+///   It is computed by power iteration until the values change less than `tolerance` in total, or for at most 10000 iterations,
+///   which is enough for any damping factor that is not extremely close to 1. A vertex without edges out jumps to a random vertex.
+///
+/// # Examples
+/// ```
+/// use itualgs_rs::graph::centrality::Centrality;
+/// use itualgs_rs::graph::graph::Graph;
+///
+/// // a star, where 0 is in the middle
+/// let mut g = Graph::new(4);
+/// g.add_edge(0, 1);
+/// g.add_edge(0, 2);
+/// g.add_edge(0, 3);
+///
+/// assert_eq!(g.degree_centrality()[0], 1.0);
+/// assert_eq!(g.closeness_centrality()[0], 1.0);
+/// assert_eq!(g.betweenness_centrality(), vec![3.0, 0.0, 0.0, 0.0]);
+///
+/// let rank = g.page_rank(0.85, 1e-9);
+/// assert!(rank[0] > rank[1]);
+/// ```
+pub trait Centrality {
+    /// The degree of every vertex divided by *V-1*
+    fn degree_centrality(&self) -> Vec<f64>;
+
+    /// The closeness of every vertex, 0 for a vertex that can not reach any other vertex
+    fn closeness_centrality(&self) -> Vec<f64>;
+
+    /// The betweenness of every vertex
+    fn betweenness_centrality(&self) -> Vec<f64>;
+
+    /// The page rank of every vertex, which sums to 1
+    fn page_rank(&self, damping: f64, tolerance: f64) -> Vec<f64>;
+}
+
+impl Centrality for Graph {
+    fn degree_centrality(&self) -> Vec<f64> {
+        let adj = adjacency(self.get_v(), |v| self.adj_vertices(&v).copied().collect());
+        degree(self.get_v(), |v| adj[v].len())
+    }
+
+    fn closeness_centrality(&self) -> Vec<f64> {
+        closeness(self.get_v(), |s| BFP::new(self, s).dist_to)
+    }
+
+    fn betweenness_centrality(&self) -> Vec<f64> {
+        let adj = adjacency(self.get_v(), |v| self.adj_vertices(&v).copied().collect());
+        brandes(&adj, |s| BFP::new(self, s).dist_to).into_iter().map(|b| b / 2.0).collect()
+    }
+
+    fn page_rank(&self, damping: f64, tolerance: f64) -> Vec<f64> {
+        let adj = adjacency(self.get_v(), |v| self.adj_vertices(&v).copied().collect());
+        page_rank(&adj, damping, tolerance)
+    }
+}
+
+impl Centrality for Digraph {
+    fn degree_centrality(&self) -> Vec<f64> {
+        let adj = adjacency(self.get_v(), |v| self.adj_vertices(&v).copied().collect());
+        degree(self.get_v(), |v| adj[v].len() + self.in_degree(v))
+    }
+
+    fn closeness_centrality(&self) -> Vec<f64> {
+        closeness(self.get_v(), |s| DirectedBFP::new(self, s).dist_to)
+    }
+
+    fn betweenness_centrality(&self) -> Vec<f64> {
+        // the shortest paths into w come from the vertices pointing to w
+        let reverse = self.reverse();
+        let preds = adjacency(self.get_v(), |v| reverse.adj_vertices(&v).copied().collect());
+        brandes(&preds, |s| DirectedBFP::new(self, s).dist_to)
+    }
+
+    fn page_rank(&self, damping: f64, tolerance: f64) -> Vec<f64> {
+        let adj = adjacency(self.get_v(), |v| self.adj_vertices(&v).copied().collect());
+        page_rank(&adj, damping, tolerance)
+    }
+}
+
+fn adjacency<F>(n: usize, neighbours: F) -> Vec<Vec<usize>>
+    where F: Fn(usize) -> Vec<usize>
+{
+    (0..n).map(neighbours).collect()
+}
+
+fn degree<F>(n: usize, degree_of: F) -> Vec<f64>
+    where F: Fn(usize) -> usize
+{
+    if n < 2 {
+        return vec![0.0; n];
+    }
+    (0..n).map(|v| degree_of(v) as f64 / (n - 1) as f64).collect()
+}
+
+fn closeness<F>(n: usize, dist_from: F) -> Vec<f64>
+    where F: Fn(usize) -> Vec<usize>
+{
+    (0..n).map(|s| {
+        let dist = dist_from(s);
+        let reached: Vec<usize> = dist.into_iter().filter(|d| *d != usize::MAX).collect();
+        let others = (reached.len() - 1) as f64;
+        let total: usize = reached.iter().sum();
+        if total == 0 {
+            return 0.0;
+        }
+        others / total as f64 * others / (n - 1) as f64
+    }).collect()
+}
+
+/// Brandes algorithm, where `preds[w]` are the vertices with an edge into w
+fn brandes<F>(preds: &[Vec<usize>], dist_from: F) -> Vec<f64>
+    where F: Fn(usize) -> Vec<usize>
+{
+    let n = preds.len();
+    let mut betweenness = vec![0.0; n];
+    for s in 0..n {
+        let dist = dist_from(s);
+        // the breath first order is the order of increasing distance
+        let mut order: Vec<usize> = (0..n).filter(|v| dist[*v] != usize::MAX).collect();
+        order.sort_by_key(|v| dist[*v]);
+
+        let on_path = |v: usize, w: usize| dist[v] != usize::MAX && dist[v] + 1 == dist[w];
+        let mut sigma = vec![0.0; n];
+        sigma[s] = 1.0;
+        for w in &order[1..] {
+            sigma[*w] = preds[*w].iter().filter(|v| on_path(**v, *w)).map(|v| sigma[*v]).sum();
+        }
+
+        let mut delta = vec![0.0; n];
+        for w in order.iter().rev() {
+            for v in preds[*w].iter().filter(|v| on_path(**v, *w)) {
+                delta[*v] += sigma[*v] / sigma[*w] * (1.0 + delta[*w]);
+            }
+            if *w != s {
+                betweenness[*w] += delta[*w];
+            }
+        }
+    }
+    betweenness
+}
+
+// the most power iterations page rank does, in case the tolerance is below what floating point rounding allows
+const MAX_ITERATIONS: usize = 10_000;
+
+fn page_rank(adj: &[Vec<usize>], damping: f64, tolerance: f64) -> Vec<f64> {
+    if !(0.0..1.0).contains(&damping) {
+        panic!("The damping factor has to be at least 0 and below 1!")
+    }
+    if tolerance.is_nan() || tolerance <= 0.0 {
+        panic!("The tolerance has to be above 0!")
+    }
+    let n = adj.len();
+    if n == 0 {
+        return Vec::new();
+    }
+    let mut rank = vec![1.0 / n as f64; n];
+    for _ in 0..MAX_ITERATIONS {
+        let dangling: f64 = (0..n).filter(|v| adj[*v].is_empty()).map(|v| rank[v]).sum();
+        let mut next = vec![(1.0 - damping + damping * dangling) / n as f64; n];
+        for (v, list) in adj.iter().enumerate() {
+            for w in list {
+                next[*w] += damping * rank[v] / list.len() as f64;
+            }
+        }
+        let change: f64 = rank.iter().zip(&next).map(|(a, b)| (a - b).abs()).sum();
+        rank = next;
+        if change < tolerance {
+            break;
+        }
+    }
+    rank
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::graph::{digraph::Digraph, graph::Graph};
+
+    use super::Centrality;
+
+    fn close(a: &[f64], b: &[f64]) -> bool {
+        a.len() == b.len() && a.iter().zip(b).all(|(x, y)| (x - y).abs() < 1e-6)
+    }
+
+    #[test]
+    fn test_path_graph(){
+        let mut g = Graph::new(5);
+        for v in 0..4 {
+            g.add_edge(v, v + 1);
+        }
+        assert!(close(&g.degree_centrality(), &[0.25, 0.5, 0.5, 0.5, 0.25]));
+        assert!(close(&g.betweenness_centrality(), &[0.0, 3.0, 4.0, 3.0, 0.0]));
+        assert!(close(&g.closeness_centrality(), &[0.4, 4.0 / 7.0, 4.0 / 6.0, 4.0 / 7.0, 0.4]));
+
+        let rank = g.page_rank(0.85, 1e-10);
+        assert!((rank.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+        assert!((rank[0] - rank[4]).abs() < 1e-9);
+        assert!(rank[1] > rank[0]);
+    }
+
+    #[test]
+    fn test_shared_shortest_paths(){
+        // a square, where 0 and 2 are connected through both 1 and 3
+        let mut g = Graph::new(4);
+        for v in 0..4 {
+            g.add_edge(v, (v + 1) % 4);
+        }
+        assert!(close(&g.betweenness_centrality(), &[0.5, 0.5, 0.5, 0.5]));
+    }
+
+    #[test]
+    fn test_disconnected_closeness(){
+        let mut g = Graph::new(4);
+        g.add_edge(0, 1);
+        let closeness = g.closeness_centrality();
+        assert!(close(&closeness, &[1.0 / 3.0, 1.0 / 3.0, 0.0, 0.0]));
+    }
+
+    #[test]
+    fn test_digraph(){
+        // 0 -> 1 -> 2, and 3 -> 1
+        let mut g = Digraph::new(4);
+        g.add_edge(0, 1);
+        g.add_edge(1, 2);
+        g.add_edge(3, 1);
+        assert!(close(&g.degree_centrality(), &[1.0 / 3.0, 1.0, 1.0 / 3.0, 1.0 / 3.0]));
+        assert!(close(&g.betweenness_centrality(), &[0.0, 2.0, 0.0, 0.0]));
+        assert!(close(&g.closeness_centrality(), &[2.0 / 3.0 * 2.0 / 3.0, 1.0 / 3.0, 0.0, 2.0 / 3.0 * 2.0 / 3.0]));
+
+        let rank = g.page_rank(0.85, 1e-10);
+        assert!((rank.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+        assert!(rank[2] > rank[1] && rank[1] > rank[0]);
+        assert!((rank[0] - rank[3]).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_page_rank_of_a_cycle_is_uniform(){
+        let mut g = Digraph::new(5);
+        for v in 0..5 {
+            g.add_edge(v, (v + 1) % 5);
+        }
+        assert!(close(&g.page_rank(0.5, 1e-12), &[0.2; 5]));
+    }
+
+    fn path(v: usize) -> Graph {
+        let mut g = Graph::new(v);
+        for v in 0..v - 1 {
+            g.add_edge(v, v + 1);
+        }
+        g
+    }
+
+    #[test]
+    fn test_page_rank_stops_below_rounding(){
+        // the total change never gets below the smallest float, so only the iteration bound stops it
+        let rank = path(3).page_rank(0.85, f64::MIN_POSITIVE);
+        assert!((rank.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+        assert!(rank[1] > rank[0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_page_rank_zero_tolerance(){
+        path(3).page_rank(0.85, 0.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_page_rank_nan_tolerance(){
+        path(3).page_rank(0.85, f64::NAN);
+    }
+}
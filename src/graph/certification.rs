@@ -0,0 +1,75 @@
+use std::{error::Error, fmt};
+
+
+/// A certification error is returned by the `check` methods of `DijkstraSP` and `KruskalMST`,
+/// when the result does not satisfy the optimality conditions it is supposed to.
+/// Every variant carries the vertices or edges that broke the condition, so it can be matched on in tests,
+/// and the `Display` implementation explains what went wrong.
+///
+/// # Examples
+/// ```
+/// use itualgs_rs::graph::certification::CertificationError;
+///
+/// let err = CertificationError::NotRelaxed { v: 0, w: 1, weight: 3 };
+/// assert_eq!(err.to_string(), "the edge 0-1 with weight 3 can still be relaxed");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CertificationError {
+    /// The graph has actual vertices, but the result was computed for a graph with expected vertices
+    GraphSizeMismatch { expected: usize, actual: usize },
+    /// The source s is not one of the v vertices of the graph
+    SourceOutOfRange { s: usize, v: usize },
+    /// The edge x-y on the path to v does not connect v to a vertex of the graph
+    EdgeNotIncident { v: usize, x: usize, y: usize, weight: u128 },
+    /// The distance to the source is not 0
+    SourceDistance { s: usize, dist: u128 },
+    /// The source has an edge on its path to itself
+    SourceHasEdge { s: usize },
+    /// A reachable vertex other than the source has no edge on its path
+    MissingEdgeTo { v: usize },
+    /// The edge on the path to w does not lead to the distance of w
+    InconsistentEdgeTo { v: usize, w: usize, weight: u128 },
+    /// The edge v-w gives a shorter path to one of its endpoints
+    NotRelaxed { v: usize, w: usize, weight: u128 },
+    /// The weight of the tree is not the sum of its edges
+    WeightMismatch { expected: u128, actual: u128 },
+    /// The edge v-w closes a cycle in the tree
+    NotAForest { v: usize, w: usize, weight: u128 },
+    /// The edge v-w connects two trees, so the forest is not spanning
+    NotSpanning { v: usize, w: usize, weight: u128 },
+    /// The edge v-w crosses the cut of the tree edge x-y but is lighter
+    CutOptimality { v: usize, w: usize, weight: u128, x: usize, y: usize, tree_weight: u128 },
+}
+
+impl fmt::Display for CertificationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CertificationError::GraphSizeMismatch { expected, actual } =>
+                write!(f, "the graph has {} vertices but the result is for {} vertices", actual, expected),
+            CertificationError::SourceOutOfRange { s, v } =>
+                write!(f, "the source {} is not a vertex of a graph with {} vertices", s, v),
+            CertificationError::EdgeNotIncident { v, x, y, weight } =>
+                write!(f, "the edge {}-{} with weight {} on the path to {} does not connect {} to the graph", x, y, weight, v, v),
+            CertificationError::SourceDistance { s, dist } =>
+                write!(f, "the distance to the source {} is {} and not 0", s, dist),
+            CertificationError::SourceHasEdge { s } =>
+                write!(f, "the source {} has an edge on its path", s),
+            CertificationError::MissingEdgeTo { v } =>
+                write!(f, "the vertex {} is reachable but has no edge on its path", v),
+            CertificationError::InconsistentEdgeTo { v, w, weight } =>
+                write!(f, "the edge {}-{} with weight {} on the path to {} does not match the distances", v, w, weight, w),
+            CertificationError::NotRelaxed { v, w, weight } =>
+                write!(f, "the edge {}-{} with weight {} can still be relaxed", v, w, weight),
+            CertificationError::WeightMismatch { expected, actual } =>
+                write!(f, "the weight is {} but the edges sum to {}", actual, expected),
+            CertificationError::NotAForest { v, w, weight } =>
+                write!(f, "the edge {}-{} with weight {} creates a cycle", v, w, weight),
+            CertificationError::NotSpanning { v, w, weight } =>
+                write!(f, "the edge {}-{} with weight {} connects two trees, so the forest is not spanning", v, w, weight),
+            CertificationError::CutOptimality { v, w, weight, x, y, tree_weight } =>
+                write!(f, "the edge {}-{} with weight {} is lighter than the tree edge {}-{} with weight {} in the same cut", v, w, weight, x, y, tree_weight),
+        }
+    }
+}
+
+impl Error for CertificationError {}
@@ -0,0 +1,277 @@
+use super::graph::Graph;
+
+
+/// Graph coloring gives every vertex a color, numbered from 0, such that no edge connects two vertices of the same color.
+/// This is what register allocation and exam scheduling boil down to, where an edge means that two variables are live at the same time
+/// or that two exams share a student.
+///
+/// There are three ways to find a coloring.
+/// * `welsh_powell` colors the vertices greedily by decreasing degree, every vertex getting the smallest color none of its neighbours have. It is *O(V log V + E)*.
+/// * `dsatur` always colors the vertex with the most differently colored neighbours next, breaking ties by degree. It is *O(V^2 + E)* and often uses fewer colors.
+/// * `exact` finds the chromatic number, the smallest amount of colors possible, by backtracking. It is exponential, so it is only for small graphs.
+///
+/// Every coloring is checked to be proper before it is returned. A graph with a self loop has no proper coloring, so all three panic on one.
+///
+/// # Examples
+/// ```
+/// use itualgs_rs::graph::coloring::GraphColoring;
+/// use itualgs_rs::graph::graph::Graph;
+///
+/// // a cycle of five vertices needs three colors
+/// let mut g = Graph::new(5);
+/// for v in 0..5 {
+///     g.add_edge(v, (v + 1) % 5);
+/// }
+/// let coloring = GraphColoring::exact(&g);
+/// assert_eq!(coloring.count(), 3);
+/// assert_ne!(coloring.color_of(0), coloring.color_of(1));
+/// assert!(GraphColoring::is_proper(&g, coloring.colors()));
+/// ```
+pub struct GraphColoring {
+    colors: Vec<usize>,
+    count: usize,
+}
+
+impl GraphColoring {
+
+    /// Colors the vertices greedily in order of decreasing degree
+    pub fn welsh_powell(g:&Graph) -> Self {
+        let adj = Self::adjacency(g);
+        let mut order: Vec<usize> = (0..adj.len()).collect();
+        order.sort_by_key(|v| std::cmp::Reverse(adj[*v].len()));
+
+        let mut colors = vec![usize::MAX; adj.len()];
+        for v in order {
+            colors[v] = Self::smallest_free(&adj[v], &colors);
+        }
+        Self::checked(g, colors)
+    }
+
+    /// Colors the vertex with the highest saturation next, which is the amount of different colors among its neighbours
+    pub fn dsatur(g:&Graph) -> Self {
+        let adj = Self::adjacency(g);
+        let n = adj.len();
+        let mut colors = vec![usize::MAX; n];
+        let mut seen: Vec<Vec<bool>> = vec![Vec::new(); n];
+        let mut saturation = vec![0; n];
+
+        for _ in 0..n {
+            let v = (0..n)
+                .filter(|v| colors[*v] == usize::MAX)
+                .max_by_key(|v| (saturation[*v], adj[*v].len(), std::cmp::Reverse(*v)))
+                .unwrap();
+            let c = Self::smallest_free(&adj[v], &colors);
+            colors[v] = c;
+            for w in &adj[v] {
+                if seen[*w].len() <= c {
+                    seen[*w].resize(c + 1, false);
+                }
+                if !seen[*w][c] {
+                    seen[*w][c] = true;
+                    saturation[*w] += 1;
+                }
+            }
+        }
+        Self::checked(g, colors)
+    }
+
+    /// Finds a coloring with the smallest possible amount of colors
+    pub fn exact(g:&Graph) -> Self {
+        let upper = Self::dsatur(g);
+        let adj = Self::adjacency(g);
+        let n = adj.len();
+        let lower = if n == 0 { 0 } else if adj.iter().all(|a| a.is_empty()) { 1 } else { 2 };
+
+        for k in lower..upper.count {
+            let mut colors = vec![usize::MAX; n];
+            if Self::backtrack(&adj, &mut colors, k, 0, 0) {
+                return Self::checked(g, colors);
+            }
+        }
+        upper
+    }
+
+    /// Tries to color the rest of the vertices with k colors, where `used` colors are in use so far
+    fn backtrack(adj: &[Vec<usize>], colors: &mut Vec<usize>, k: usize, colored: usize, used: usize) -> bool {
+        let n = adj.len();
+        if colored == n {
+            return true;
+        }
+        // the uncolored vertex with the most different colors around it has the fewest options
+        let mut best = usize::MAX;
+        let mut best_saturation = 0;
+        let mut free = vec![true; k];
+        for v in (0..n).filter(|v| colors[*v] == usize::MAX) {
+            let mut taken = vec![false; k];
+            for w in &adj[v] {
+                if colors[*w] != usize::MAX {
+                    taken[colors[*w]] = true;
+                }
+            }
+            let saturation = taken.iter().filter(|t| **t).count();
+            if best == usize::MAX || saturation > best_saturation {
+                best = v;
+                best_saturation = saturation;
+                free = taken.iter().map(|t| !t).collect();
+            }
+        }
+
+        // the colors above `used` are all the same, so only one of them is tried
+        for (c, _) in free.iter().enumerate().take(used + 1).filter(|(_, f)| **f) {
+            colors[best] = c;
+            if Self::backtrack(adj, colors, k, colored + 1, used.max(c + 1)) {
+                return true;
+            }
+        }
+        colors[best] = usize::MAX;
+        false
+    }
+
+    fn smallest_free(neighbours: &[usize], colors: &[usize]) -> usize {
+        let mut taken = vec![false; neighbours.len() + 1];
+        for w in neighbours {
+            if colors[*w] < taken.len() {
+                taken[colors[*w]] = true;
+            }
+        }
+        taken.iter().position(|t| !t).unwrap()
+    }
+
+    fn adjacency(g:&Graph) -> Vec<Vec<usize>> {
+        let mut adj: Vec<Vec<usize>> = Vec::with_capacity(g.get_v());
+        for v in 0..g.get_v() {
+            let mut list: Vec<usize> = g.adj_vertices(&v).copied().collect();
+            if list.contains(&v) {
+                panic!("A graph with a self loop can not be colored!")
+            }
+            list.sort_unstable();
+            list.dedup();
+            adj.push(list);
+        }
+        adj
+    }
+
+    fn checked(g:&Graph, colors: Vec<usize>) -> Self {
+        if !Self::is_proper(g, &colors) {
+            panic!("The coloring is not proper!")
+        }
+        let count = colors.iter().max().map_or(0, |c| c + 1);
+        GraphColoring { colors, count }
+    }
+
+    /// Checks that every vertex has a color and that no edge connects two vertices of the same color
+    pub fn is_proper(g:&Graph, colors: &[usize]) -> bool {
+        if colors.len() != g.get_v() || colors.contains(&usize::MAX) {
+            return false;
+        }
+        (0..g.get_v()).all(|v| g.adj_vertices(&v).all(|w| colors[*w] != colors[v]))
+    }
+
+    /// The color of v
+    pub fn color_of(&self, v: usize) -> usize {
+        self.colors[v]
+    }
+
+    /// The colors of all the vertices
+    pub fn colors(&self) -> &[usize] {
+        &self.colors
+    }
+
+    /// The amount of colors used
+    pub fn count(&self) -> usize {
+        self.count
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::graph::graph::Graph;
+    use crate::randomization::lcg_random::lcg_generate_seeded;
+
+    use super::GraphColoring;
+
+    fn build(v: usize, edges: &[(usize, usize)]) -> Graph {
+        let mut g = Graph::new(v);
+        for (a, b) in edges {
+            g.add_edge(*a, *b);
+        }
+        g
+    }
+
+    #[test]
+    fn test_bipartite(){
+        // a crown graph, where a bad greedy order needs four colors
+        let g = build(8, &[(0, 3), (0, 5), (0, 7), (2, 1), (2, 5), (2, 7), (4, 1), (4, 3), (4, 7), (6, 1), (6, 3), (6, 5)]);
+        assert_eq!(GraphColoring::dsatur(&g).count(), 2);
+        assert_eq!(GraphColoring::exact(&g).count(), 2);
+        // every vertex has degree 3, so Welsh-Powell colors 0 to 7 in order and gives the pairs 0 1, 2 3, 4 5 and 6 7 a color each
+        let greedy = GraphColoring::welsh_powell(&g);
+        assert_eq!(greedy.count(), 4);
+        assert_eq!(greedy.colors(), &[0, 0, 1, 1, 2, 2, 3, 3]);
+        for coloring in [greedy, GraphColoring::dsatur(&g), GraphColoring::exact(&g)] {
+            assert!(GraphColoring::is_proper(&g, coloring.colors()));
+        }
+    }
+
+    #[test]
+    fn test_complete_graph(){
+        let mut edges = Vec::new();
+        for v in 0..6 {
+            for w in v + 1..6 {
+                edges.push((v, w));
+            }
+        }
+        let g = build(6, &edges);
+        assert_eq!(GraphColoring::welsh_powell(&g).count(), 6);
+        assert_eq!(GraphColoring::exact(&g).count(), 6);
+    }
+
+    #[test]
+    fn test_empty_graphs(){
+        assert_eq!(GraphColoring::exact(&Graph::new(0)).count(), 0);
+        assert_eq!(GraphColoring::exact(&Graph::new(3)).count(), 1);
+    }
+
+    #[test]
+    fn test_exact_is_optimal(){
+        let v = 8;
+        let rand = lcg_generate_seeded(40, 32);
+        let mut edges = Vec::new();
+        for c in rand.chunks(2) {
+            let (a, b) = (c[0] as usize % v, c[1] as usize % v);
+            if a != b {
+                edges.push((a, b));
+            }
+        }
+        let g = build(v, &edges);
+        let exact = GraphColoring::exact(&g);
+        assert!(exact.count() <= GraphColoring::dsatur(&g).count());
+        assert!(exact.count() <= GraphColoring::welsh_powell(&g).count());
+
+        // no coloring with fewer colors exists
+        let k = exact.count() - 1;
+        let mut colors = vec![0; v];
+        let mut found = false;
+        for _ in 0..k.pow(v as u32) {
+            if GraphColoring::is_proper(&g, &colors) {
+                found = true;
+                break;
+            }
+            for c in colors.iter_mut() {
+                *c += 1;
+                if *c < k {
+                    break;
+                }
+                *c = 0;
+            }
+        }
+        assert!(!found);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_self_loop(){
+        GraphColoring::dsatur(&build(2, &[(0, 1), (1, 1)]));
+    }
+}
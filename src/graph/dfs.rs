@@ -21,7 +21,7 @@ use std::collections::{linked_list::Iter};
 /// let mut g = Graph::new(4);
 /// g.add_edge(0, 1);
 /// g.add_edge(0, 2);
-/// let paths = DFS::new(&mut g, 2);
+/// let paths = DFS::new(&g, 2);
 /// assert_eq!(paths.marked[1], true);
 /// assert_eq!(paths.marked[0], true);
 /// assert_eq!(paths.marked[2], true);
@@ -35,12 +35,17 @@ pub struct DFS{
 impl DFS {
 
     /// creates a new marked list from a graph
-    pub fn new(g:&mut Graph, s: usize) -> DFS{
-        DFS { marked: Self::dfs(g,s) }
+    pub fn new(g:&Graph, s: usize) -> DFS{
+        DFS { marked: Self::dfs(g,&[s]) }
+    }
+
+    /// creates a new marked list of the vertices connected to any of the sources
+    pub fn new_from_sources(g:&Graph, sources: &[usize]) -> DFS{
+        DFS { marked: Self::dfs(g,sources) }
     }
 
 
-    fn dfs(g:&mut Graph, s: usize) -> Vec<bool>{
+    fn dfs(g:&Graph, sources: &[usize]) -> Vec<bool>{
         let mut m:Vec<bool> = vec![false;g.get_v()];
         let mut adj: Vec<Iter<usize>> = Vec::with_capacity(g.get_v());
 
@@ -49,17 +54,22 @@ impl DFS {
         }
     
         let mut stack = Stack::<usize>::new();
-        m[s] = true;
-        stack.push(s);
-        while !stack.is_empty() {
-            let v = stack.peek().unwrap();
-            if let Some(w) = adj[*v].next(){
-                if !m[*w] {
-                    m[*w] = true;
-                    stack.push(*w);
+        for s in sources {
+            if m[*s] {
+                continue;
+            }
+            m[*s] = true;
+            stack.push(*s);
+            while !stack.is_empty() {
+                let v = stack.peek().unwrap();
+                if let Some(w) = adj[*v].next(){
+                    if !m[*w] {
+                        m[*w] = true;
+                        stack.push(*w);
+                    }
+                } else {
+                    stack.pop();
                 }
-            } else {
-                stack.pop();
             }
         }
         m
@@ -78,10 +88,20 @@ mod tests {
         let mut g = Graph::new(4);
         g.add_edge(0, 1);
         g.add_edge(0, 2);
-        let paths = DFS::new(&mut g, 2);
+        let paths = DFS::new(&g, 2);
         assert_eq!(paths.marked[1], true);
         assert_eq!(paths.marked[0], true);
         assert_eq!(paths.marked[2], true);
         assert_eq!(paths.marked[3], false);
     }
+
+    #[test]
+    fn test_from_sources(){
+        let mut g = Graph::new(6);
+        g.add_edge(0, 1);
+        g.add_edge(2, 3);
+        g.add_edge(4, 5);
+        let paths = DFS::new_from_sources(&g, &[1, 3]);
+        assert_eq!(paths.marked, vec![true, true, true, true, false, false]);
+    }
 }
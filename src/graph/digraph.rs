@@ -0,0 +1,133 @@
+use crate::fundamentals::bag::Bag;
+
+
+/// A digraph is a graph where every edge has a direction, so the edge v->w lets you go from v to w but not back.
+/// The vertices are numbered from 0 to V-1 and the edges are kept in an adjacency list of bags.
+///
+/// `add_edge`, `out_degree` and `in_degree` are constant time, `reverse` is *O(V+E)*.
+///
+/// # Examples
+/// ```
+/// use itualgs_rs::graph::digraph::Digraph;
+///
+/// let mut g = Digraph::new(3);
+/// g.add_edge(0, 1);
+/// g.add_edge(0, 2);
+/// assert_eq!(g.out_degree(0), 2);
+/// assert_eq!(g.in_degree(0), 0);
+/// assert_eq!(g.reverse().out_degree(1), 1);
+/// ```
+pub struct Digraph {
+    v: usize,
+    e: usize,
+    adj: Vec<Bag<usize>>,
+    in_degree: Vec<usize>,
+}
+
+impl Digraph {
+
+    /// Inits a new digraph with V vertices
+    pub fn new(v:usize) -> Digraph {
+        let mut g = Digraph { v, e: 0, adj: Vec::with_capacity(v), in_degree: vec![0; v] };
+        for _ in 0..v {
+            g.adj.push(Bag::<usize>::new());
+        }
+        g
+    }
+
+    /// adds the directed edge v->w
+    pub fn add_edge(&mut self, v:usize, w:usize){
+        self.validate(v);
+        self.validate(w);
+        self.e += 1;
+        self.adj[v].add(w);
+        self.in_degree[w] += 1;
+    }
+
+    /// returns the vertices pointed to from v
+    pub fn adj_vertices(&self, v:&usize) -> std::collections::linked_list::Iter<'_, usize> {
+        self.validate(*v);
+        self.adj[*v].iterator()
+    }
+
+    /// The amount of edges leaving v
+    pub fn out_degree(&mut self, v:usize) -> usize {
+        self.validate(v);
+        self.adj[v].size()
+    }
+
+    /// The amount of edges pointing to v
+    pub fn in_degree(&self, v:usize) -> usize {
+        self.validate(v);
+        self.in_degree[v]
+    }
+
+    /// Returns a copy of the digraph with every edge reversed
+    pub fn reverse(&self) -> Digraph {
+        let mut r = Digraph::new(self.v);
+        for v in 0..self.v {
+            for w in self.adj_vertices(&v) {
+                r.add_edge(*w, v);
+            }
+        }
+        r
+    }
+
+    fn validate(&self, p:usize){
+        if p>= self.v {
+            panic!("The index is out of bounds!")
+        }
+    }
+
+    pub fn get_v(&self) -> usize {
+        self.v
+    }
+
+    pub fn get_e(&self) -> usize {
+        self.e
+    }
+}
+
+impl Clone for Digraph {
+    /// Creates a deep copy of the digraph
+    fn clone(&self) -> Digraph {
+        let mut temp: Vec<Bag<usize>> = Vec::with_capacity(self.v);
+        for i in &self.adj {
+            temp.push(i.clone());
+        }
+        Digraph { v: self.v, e: self.e, adj: temp, in_degree: self.in_degree.clone() }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::Digraph;
+
+    #[test]
+    fn test_create_digraph(){
+        let mut g = Digraph::new(4);
+        g.add_edge(0, 1);
+        g.add_edge(0, 2);
+        g.add_edge(3, 0);
+        let mut it = g.adj_vertices(&0);
+        assert_eq!(*it.next().unwrap(), 2);
+        assert_eq!(*it.next().unwrap(), 1);
+        assert_eq!(it.next(), None);
+        assert_eq!(g.adj_vertices(&1).next(), None);
+        assert_eq!(g.in_degree(0), 1);
+        assert_eq!(g.get_e(), 3);
+    }
+
+    #[test]
+    fn test_reverse(){
+        let mut g = Digraph::new(3);
+        g.add_edge(0, 1);
+        g.add_edge(1, 2);
+        let mut r = g.reverse();
+        assert_eq!(r.get_e(), 2);
+        assert_eq!(*r.adj_vertices(&2).next().unwrap(), 1);
+        assert_eq!(r.out_degree(0), 0);
+        assert_eq!(r.in_degree(0), 1);
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{sorting::index_min_pq::IndexMinPQ, fundamentals::stack::Stack};
 
-use super::{edge::Edge, edge_weighted_graph::EdgeWeightedGraph, graph};
+use super::{certification::CertificationError, edge::Edge, edge_weighted_graph::EdgeWeightedGraph};
 
 
 
@@ -92,6 +92,56 @@ impl DijkstraSP {
         Some(s)
     }
 
+    /// Certifies that the shortest path tree from s is optimal for the graph g.
+    /// The distance to s has to be 0, every edge on a path has to match the distances of its endpoints
+    /// and no edge of g may give a shorter path. Is *O(E+V)*.
+    /// A graph of another size, a source outside the graph or an edge which does not touch its vertex is reported as an error too.
+    pub fn check(&self, g: &EdgeWeightedGraph, s: usize) -> Result<(), CertificationError> {
+        if g.V != self.dist_to.len() {
+            return Err(CertificationError::GraphSizeMismatch { expected: self.dist_to.len(), actual: g.V });
+        }
+        if s >= g.V {
+            return Err(CertificationError::SourceOutOfRange { s, v: g.V });
+        }
+        if self.dist_to[s] != 0 {
+            return Err(CertificationError::SourceDistance { s, dist: self.dist_to[s] });
+        }
+        if self.edge_to[s].is_some() {
+            return Err(CertificationError::SourceHasEdge { s });
+        }
+
+        for w in 0..g.V {
+            if w == s {
+                continue;
+            }
+            match &self.edge_to[w] {
+                None if self.has_path_to(&w) => return Err(CertificationError::MissingEdgeTo { v: w }),
+                None => (),
+                Some(e) => {
+                    let x = *e.either();
+                    let y = *e.other(x);
+                    if (x != w && y != w) || x >= g.V || y >= g.V {
+                        return Err(CertificationError::EdgeNotIncident { v: w, x, y, weight: e.weight });
+                    }
+                    let v = if x == w { y } else { x };
+                    if self.dist_to[v].checked_add(e.weight) != Some(self.dist_to[w]) {
+                        return Err(CertificationError::InconsistentEdgeTo { v, w, weight: e.weight });
+                    }
+                }
+            }
+        }
+
+        for e in g.edges().iterator() {
+            let v = *e.either();
+            let w = *e.other(v);
+            for (from, to) in [(v, w), (w, v)] {
+                if self.dist_to[from].saturating_add(e.weight) < self.dist_to[to] {
+                    return Err(CertificationError::NotRelaxed { v: from, w: to, weight: e.weight });
+                }
+            }
+        }
+        Ok(())
+    }
 
     fn relax(&mut self, e:&Edge, v: &usize){
         let w = e.other(*v);
@@ -138,4 +188,62 @@ mod tests {
         assert_eq!(path.pop().unwrap().weight, 2);
         assert_eq!(path.is_empty(), true);
     }
+
+    fn tiny_graph() -> EdgeWeightedGraph {
+        let mut g = EdgeWeightedGraph::new(5);
+        let list = vec![
+            Edge::new(0, 1, 10),
+            Edge::new(2, 1, 2),
+            Edge::new(2, 0, 20),
+            Edge::new(1, 3, 7)];
+        for ele in list {
+            g.add_edge(ele);
+        }
+        g
+    }
+
+    #[test]
+    fn test_check(){
+        let sp = DijkstraSP::new(tiny_graph(), 0);
+        assert_eq!(sp.check(&tiny_graph(), 0), Ok(()));
+    }
+
+    #[test]
+    fn test_check_finds_errors(){
+        use crate::graph::certification::CertificationError;
+
+        let mut sp = DijkstraSP::new(tiny_graph(), 0);
+        sp.dist_to[2] = 30;
+        assert_eq!(sp.check(&tiny_graph(), 0), Err(CertificationError::InconsistentEdgeTo { v: 1, w: 2, weight: 2 }));
+
+        let mut sp = DijkstraSP::new(tiny_graph(), 0);
+        sp.edge_to[2] = Some(Edge::new(2, 0, 20));
+        sp.dist_to[2] = 20;
+        assert_eq!(sp.check(&tiny_graph(), 0), Err(CertificationError::NotRelaxed { v: 1, w: 2, weight: 2 }));
+
+        let mut sp = DijkstraSP::new(tiny_graph(), 0);
+        sp.edge_to[3] = None;
+        assert_eq!(sp.check(&tiny_graph(), 0), Err(CertificationError::MissingEdgeTo { v: 3 }));
+
+        let sp = DijkstraSP::new(tiny_graph(), 0);
+        assert_eq!(sp.check(&tiny_graph(), 1), Err(CertificationError::SourceDistance { s: 1, dist: 10 }));
+    }
+
+    #[test]
+    fn test_check_finds_mismatches(){
+        use crate::graph::certification::CertificationError;
+
+        let sp = DijkstraSP::new(tiny_graph(), 0);
+        assert_eq!(sp.check(&tiny_graph(), 5), Err(CertificationError::SourceOutOfRange { s: 5, v: 5 }));
+        assert_eq!(sp.check(&EdgeWeightedGraph::new(3), 0), Err(CertificationError::GraphSizeMismatch { expected: 5, actual: 3 }));
+        assert_eq!(sp.check(&EdgeWeightedGraph::new(8), 0), Err(CertificationError::GraphSizeMismatch { expected: 5, actual: 8 }));
+
+        let mut sp = DijkstraSP::new(tiny_graph(), 0);
+        sp.edge_to[3] = Some(Edge::new(0, 1, 7));
+        assert_eq!(sp.check(&tiny_graph(), 0), Err(CertificationError::EdgeNotIncident { v: 3, x: 0, y: 1, weight: 7 }));
+
+        let mut sp = DijkstraSP::new(tiny_graph(), 0);
+        sp.edge_to[3] = Some(Edge::new(3, 9, 7));
+        assert_eq!(sp.check(&tiny_graph(), 0), Err(CertificationError::EdgeNotIncident { v: 3, x: 3, y: 9, weight: 7 }));
+    }
 }
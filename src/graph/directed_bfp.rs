@@ -0,0 +1,116 @@
+use crate::fundamentals::{stack::Stack, queue::Queue};
+
+use super::digraph::Digraph;
+
+
+/// Directed breath first paths is the breath first paths of a digraph, where the edges are only followed in their direction.
+/// The `DirectedBFP::new()` has a running time of *O(V+E)* where *V* is the amount of vertices and *E* the amount of edges.
+///
+/// DirectedBFP finds a shortest directed path tree from a vertex s, so `get_dist_to` is the fewest edges needed to go from s to v.
+///
+/// # Examples
+/// ```
+/// use itualgs_rs::graph::directed_bfp::DirectedBFP;
+/// use itualgs_rs::graph::digraph::Digraph;
+///
+/// let mut g = Digraph::new(4);
+/// g.add_edge(0, 1);
+/// g.add_edge(1, 2);
+/// g.add_edge(3, 0);
+/// let paths = DirectedBFP::new(&g, 0);
+/// assert_eq!(paths.get_dist_to(2), 2);
+/// assert_eq!(paths.has_path_to(3), false);
+/// let mut s = paths.get_path_to(2).unwrap();
+/// assert_eq!(s.pop().unwrap(), 0);
+/// assert_eq!(s.pop().unwrap(), 1);
+/// assert_eq!(s.pop().unwrap(), 2);
+/// ```
+pub struct DirectedBFP{
+    pub marked: Vec<bool>,
+    pub edge_to: Vec<usize>,
+    pub dist_to: Vec<usize>,
+}
+
+impl DirectedBFP {
+
+    /// runs a breath first search from s
+    pub fn new(g:&Digraph, s: usize) -> DirectedBFP{
+        let mut paths = DirectedBFP {
+            marked: vec![false;g.get_v()],
+            edge_to: vec![0;g.get_v()],
+            dist_to: vec![usize::MAX;g.get_v()],
+        };
+        let mut q = Queue::<usize>::new();
+
+        paths.marked[s] = true;
+        paths.dist_to[s] = 0;
+        q.enqueue(s);
+        while let Some(v) = q.dequeue() {
+            for w in g.adj_vertices(&v){
+                if !paths.marked[*w] {
+                    paths.edge_to[*w] = v;
+                    paths.dist_to[*w] = paths.dist_to[v]+1;
+                    paths.marked[*w] = true;
+                    q.enqueue(*w);
+                }
+            }
+        }
+        paths
+    }
+
+    fn validate(&self, p:usize){
+        if p>=self.marked.len() {
+            panic!("Index out of bounds")
+        }
+    }
+
+    /// Does the source node have a path to v
+    pub fn has_path_to(&self, v:usize) -> bool{
+        self.validate(v);
+        self.marked[v]
+    }
+
+    /// Get dist from the source vertex to v
+    pub fn get_dist_to(&self, v:usize) -> usize {
+        self.validate(v);
+        self.dist_to[v]
+    }
+
+    /// Gets the vertices on the path from the source to v, with the source on the top of the stack
+    pub fn get_path_to(&self, v:usize) -> Option<Stack<usize>> {
+        if !self.has_path_to(v) {
+            return None;
+        }
+        let mut x = v;
+        let mut stack = Stack::<usize>::new();
+        while self.dist_to[x] != 0 {
+            stack.push(x);
+            x = self.edge_to[x];
+        }
+        stack.push(x);
+        Some(stack)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::graph::digraph::Digraph;
+
+    use super::DirectedBFP;
+
+    #[test]
+    fn test_shortest_directed_path(){
+        let mut g = Digraph::new(5);
+        g.add_edge(0, 1);
+        g.add_edge(1, 2);
+        g.add_edge(2, 3);
+        g.add_edge(0, 3);
+        g.add_edge(4, 0);
+        let paths = DirectedBFP::new(&g, 0);
+        assert_eq!(paths.get_dist_to(3), 1);
+        assert_eq!(paths.get_dist_to(2), 2);
+        assert!(!paths.has_path_to(4));
+        assert!(paths.get_path_to(4).is_none());
+    }
+}
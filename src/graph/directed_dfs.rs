@@ -0,0 +1,117 @@
+use crate::fundamentals::stack::Stack;
+
+use super::digraph::Digraph;
+use std::collections::linked_list::Iter;
+
+
+/// Directed depth first search finds the vertices that can be reached from a source vertex, or from a set of source vertices, in a digraph.
+/// Starting from a set of sources is what the mark phase of a mark-and-sweep garbage collector does, where the sources are the roots.
+/// The `DirectedDFS::new()` has a running time of *O(V+E)* where *V* is the amount of vertices and *E* the amount of edges.
+///
+/// DirectedDFS contains a vector of booleans to check weather the vertices are reachable or not.
+///
+/// # Examples
+/// ```
+/// use itualgs_rs::graph::directed_dfs::DirectedDFS;
+/// use itualgs_rs::graph::digraph::Digraph;
+///
+/// let mut g = Digraph::new(5);
+/// g.add_edge(0, 1);
+/// g.add_edge(2, 1);
+/// g.add_edge(3, 4);
+/// let reachable = DirectedDFS::new_from_sources(&g, &[0, 2]);
+/// assert_eq!(reachable.marked, vec![true, true, true, false, false]);
+/// assert_eq!(reachable.count(), 3);
+/// ```
+pub struct DirectedDFS {
+    pub marked: Vec<bool>,
+    count: usize,
+}
+
+impl DirectedDFS {
+
+    /// finds the vertices reachable from s
+    pub fn new(g:&Digraph, s: usize) -> DirectedDFS {
+        Self::new_from_sources(g, &[s])
+    }
+
+    /// finds the vertices reachable from any of the sources
+    pub fn new_from_sources(g:&Digraph, sources: &[usize]) -> DirectedDFS {
+        let mut m:Vec<bool> = vec![false;g.get_v()];
+        let mut adj: Vec<Iter<usize>> = Vec::with_capacity(g.get_v());
+
+        for v in 0..g.get_v() {
+            adj.push(g.adj_vertices(&v))
+        }
+
+        let mut count = 0;
+        let mut stack = Stack::<usize>::new();
+        for s in sources {
+            if m[*s] {
+                continue;
+            }
+            m[*s] = true;
+            count += 1;
+            stack.push(*s);
+            while let Some(v) = stack.peek() {
+                if let Some(w) = adj[*v].next(){
+                    if !m[*w] {
+                        m[*w] = true;
+                        count += 1;
+                        stack.push(*w);
+                    }
+                } else {
+                    stack.pop();
+                }
+            }
+        }
+        DirectedDFS { marked: m, count }
+    }
+
+    /// is v reachable from the sources
+    pub fn is_marked(&self, v: usize) -> bool {
+        self.marked[v]
+    }
+
+    /// the amount of vertices reachable from the sources
+    pub fn count(&self) -> usize {
+        self.count
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::graph::digraph::Digraph;
+
+    use super::DirectedDFS;
+
+    #[test]
+    fn test_direction_matters(){
+        let mut g = Digraph::new(4);
+        g.add_edge(0, 1);
+        g.add_edge(1, 2);
+        g.add_edge(3, 2);
+        let dfs = DirectedDFS::new(&g, 0);
+        assert_eq!(dfs.marked, vec![true, true, true, false]);
+
+        let dfs = DirectedDFS::new(&g, 2);
+        assert_eq!(dfs.count(), 1);
+        assert!(!dfs.is_marked(3));
+    }
+
+    #[test]
+    fn test_garbage_collection(){
+        // the heap objects 0..6, with the roots 0 and 5
+        let mut g = Digraph::new(7);
+        g.add_edge(0, 1);
+        g.add_edge(1, 2);
+        g.add_edge(2, 0);
+        g.add_edge(3, 4);
+        g.add_edge(5, 2);
+        g.add_edge(6, 5);
+        let live = DirectedDFS::new_from_sources(&g, &[0, 5]);
+        let garbage: Vec<usize> = (0..7).filter(|v| !live.is_marked(*v)).collect();
+        assert_eq!(garbage, vec![3, 4, 6]);
+    }
+}
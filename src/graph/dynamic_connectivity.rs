@@ -0,0 +1,210 @@
+use std::collections::HashMap;
+
+use crate::fundamentals::uf::RollbackUF;
+
+
+/// Dynamic connectivity answers if two vertices are connected in a graph where edges are both added and removed over time.
+/// The events are given as a log of `link`, `cut` and `query` calls, and `solve` answers every query at the point in the log where it was asked.
+///
+/// The log is solved offline by divide and conquer. Every edge is alive during an interval of the log, and the interval is stored in the
+/// *O(log T)* nodes of a segment tree over the *T* events that cover it. A depth first walk of the segment tree unions the edges of a node on the way down
+/// and rolls the unions back on the way up, so a query at a leaf sees exactly the edges alive at that time.
+/// The unions are done in a `RollbackUF`, where a union is *O(log V)* and undoing it is constant time.
+/// In total `solve` is *O(T log T log V)*.
+///
+/// The graph is a multigraph, so an edge that is linked twice has to be cut twice before it is gone.
+///
+/// # Examples
+/// ```
+/// use itualgs_rs::graph::dynamic_connectivity::DynamicConnectivity;
+///
+/// let mut dc = DynamicConnectivity::new(3);
+/// dc.link(0, 1);
+/// dc.link(1, 2);
+/// dc.query(0, 2);
+/// dc.cut(0, 1);
+/// dc.query(0, 2);
+/// dc.query(1, 2);
+/// assert_eq!(dc.solve(), vec![true, false, true]);
+/// ```
+pub struct DynamicConnectivity {
+    v: usize,
+    events: Vec<Event>,
+    // the amount of copies of every edge alive at the end of the log
+    alive: HashMap<(usize, usize), usize>,
+}
+
+enum Event {
+    Link(usize, usize),
+    Cut(usize, usize),
+    Query(usize, usize),
+}
+
+impl DynamicConnectivity {
+
+    /// Creates an empty log over v vertices
+    pub fn new(v: usize) -> Self {
+        DynamicConnectivity { v, events: Vec::new(), alive: HashMap::new() }
+    }
+
+    /// Adds the edge v-w
+    pub fn link(&mut self, v: usize, w: usize){
+        let key = self.key(v, w);
+        *self.alive.entry(key).or_insert(0) += 1;
+        self.events.push(Event::Link(key.0, key.1));
+    }
+
+    /// Removes one copy of the edge v-w, which has to be in the graph
+    pub fn cut(&mut self, v: usize, w: usize){
+        let key = self.key(v, w);
+        match self.alive.get_mut(&key) {
+            Some(count) if *count > 0 => *count -= 1,
+            _ => panic!("The edge is not in the graph!"),
+        }
+        self.events.push(Event::Cut(key.0, key.1));
+    }
+
+    /// Asks if v and w are connected at this point of the log
+    pub fn query(&mut self, v: usize, w: usize){
+        self.validate(v);
+        self.validate(w);
+        self.events.push(Event::Query(v, w));
+    }
+
+    /// The answers to the queries, in the order they were asked
+    pub fn solve(&self) -> Vec<bool> {
+        let t = self.events.len();
+        if t == 0 {
+            return Vec::new();
+        }
+        let mut tree: Vec<Vec<(usize, usize)>> = vec![Vec::new(); 4 * t];
+
+        // the times every copy of an edge was linked, which are matched with the cuts
+        let mut linked: HashMap<(usize, usize), Vec<usize>> = HashMap::new();
+        for (time, event) in self.events.iter().enumerate() {
+            match event {
+                Event::Link(v, w) => linked.entry((*v, *w)).or_default().push(time),
+                Event::Cut(v, w) => {
+                    let start = linked.get_mut(&(*v, *w)).and_then(|s| s.pop()).unwrap();
+                    Self::insert(&mut tree, 1, 0, t, start, time, (*v, *w));
+                }
+                Event::Query(_, _) => (),
+            }
+        }
+        for (edge, starts) in linked {
+            for start in starts {
+                Self::insert(&mut tree, 1, 0, t, start, t, edge);
+            }
+        }
+
+        let mut uf = RollbackUF::new(self.v);
+        let mut answers = Vec::new();
+        self.walk(&tree, 1, 0, t, &mut uf, &mut answers);
+        answers
+    }
+
+    /// Adds the edge to the nodes covering the times from l (inclusive) to r (exclusive)
+    fn insert(tree: &mut Vec<Vec<(usize, usize)>>, node: usize, lo: usize, hi: usize, l: usize, r: usize, edge: (usize, usize)){
+        if r <= lo || hi <= l {
+            return;
+        }
+        if l <= lo && hi <= r {
+            tree[node].push(edge);
+            return;
+        }
+        let mid = lo + (hi - lo) / 2;
+        Self::insert(tree, 2 * node, lo, mid, l, r, edge);
+        Self::insert(tree, 2 * node + 1, mid, hi, l, r, edge);
+    }
+
+    fn walk(&self, tree: &[Vec<(usize, usize)>], node: usize, lo: usize, hi: usize, uf: &mut RollbackUF, answers: &mut Vec<bool>){
+        let snapshot = uf.snapshot();
+        for (v, w) in &tree[node] {
+            uf.union(*v, *w);
+        }
+        if hi - lo == 1 {
+            if let Event::Query(v, w) = self.events[lo] {
+                answers.push(uf.connected(v, w));
+            }
+        } else {
+            let mid = lo + (hi - lo) / 2;
+            self.walk(tree, 2 * node, lo, mid, uf, answers);
+            self.walk(tree, 2 * node + 1, mid, hi, uf, answers);
+        }
+        uf.rollback(snapshot);
+    }
+
+    /// An edge is stored with the smaller endpoint first, so v-w and w-v are the same edge
+    fn key(&self, v: usize, w: usize) -> (usize, usize) {
+        self.validate(v);
+        self.validate(w);
+        (v.min(w), v.max(w))
+    }
+
+    fn validate(&self, p: usize){
+        if p >= self.v {
+            panic!("The index is out of bounds!")
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::fundamentals::uf::WeightedQuickUnionUF;
+    use crate::randomization::lcg_random::lcg_generate_seeded;
+
+    use super::DynamicConnectivity;
+
+    #[test]
+    fn test_multi_edges(){
+        let mut dc = DynamicConnectivity::new(2);
+        dc.link(0, 1);
+        dc.link(1, 0);
+        dc.cut(0, 1);
+        dc.query(0, 1);
+        dc.cut(0, 1);
+        dc.query(1, 0);
+        dc.query(1, 1);
+        assert_eq!(dc.solve(), vec![true, false, true]);
+    }
+
+    #[test]
+    fn test_same_as_brute_force(){
+        let v = 8;
+        let rand = lcg_generate_seeded(900, 36);
+        let mut dc = DynamicConnectivity::new(v);
+        let mut edges: Vec<(usize, usize)> = Vec::new();
+        let mut expected = Vec::new();
+        for c in rand.chunks(3) {
+            let (a, b) = (c[1] as usize % v, c[2] as usize % v);
+            match c[0] % 3 {
+                0 => {
+                    dc.link(a, b);
+                    edges.push((a, b));
+                }
+                1 if !edges.is_empty() => {
+                    let (x, y) = edges.swap_remove(a % edges.len());
+                    dc.cut(y, x);
+                }
+                _ => {
+                    dc.query(a, b);
+                    let mut uf = WeightedQuickUnionUF::new(v);
+                    for (x, y) in &edges {
+                        uf.union(*x, *y);
+                    }
+                    expected.push(uf.connected(a, b));
+                }
+            }
+        }
+        assert_eq!(dc.solve(), expected);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_cut_missing_edge(){
+        let mut dc = DynamicConnectivity::new(3);
+        dc.link(0, 1);
+        dc.cut(1, 2);
+    }
+}
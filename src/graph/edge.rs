@@ -12,9 +12,9 @@ use std::cmp::Ordering;
 /// 
 /// let mut edge = Edge::new(1, 2, 3);
 /// let either = edge.either();
-/// let other = edge.other(either);
-/// assert_eq!(either, 1);
-/// assert_eq!(other, 2);
+/// let other = edge.other(*either);
+/// assert_eq!(*either, 1);
+/// assert_eq!(*other, 2);
 /// ```
 #[derive(Clone)]
 pub struct Edge {
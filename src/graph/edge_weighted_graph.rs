@@ -59,10 +59,117 @@ impl EdgeWeightedGraph {
         list
     }
 
+    /// checks if there is an edge between v and w
+    pub fn has_edge(&self, v:&usize, w:&usize) -> bool {
+        self.validate(w);
+        self.adj(v).any(|e| e.other(*v) == w)
+    }
+
+    /// removes one edge with the same endpoints and weight as e, returns false if there was no such edge
+    pub fn remove_edge(&mut self, e:&Edge) -> bool {
+        let v = *e.either();
+        let w = *e.other(v);
+        self.validate(&v);
+        self.validate(&w);
+        let matches = |x: &Rc<Edge>| {
+            let a = *x.either();
+            let b = *x.other(a);
+            x.weight == e.weight && ((a == v && b == w) || (a == w && b == v))
+        };
+        if self.adj[v].remove_where(matches).is_none() {
+            return false;
+        }
+        self.adj[w].remove_where(matches);
+        self.E -= 1;
+        true
+    }
+
+    /// adds a new isolated vertex and returns its index
+    pub fn add_vertex(&mut self) -> usize {
+        self.adj.push(Bag::<Rc<Edge>>::new());
+        self.V += 1;
+        self.V - 1
+    }
+
+    /// removes v and all edges incident to it.
+    /// The vertices after v are reindexed, so vertex w > v becomes w - 1.
+    /// This is *O(V+E)*, since every edge has to be rebuilt.
+    pub fn remove_vertex(&mut self, v:&usize){
+        self.validate(v);
+        let edges = self.edges();
+        let reindex = |x: usize| if x > *v { x - 1 } else { x };
+
+        let mut g = EdgeWeightedGraph::new(self.V - 1);
+        for e in edges.iterator() {
+            let a = *e.either();
+            let b = *e.other(a);
+            if a != *v && b != *v {
+                g.add_edge(Edge::new(reindex(a), reindex(b), e.weight));
+            }
+        }
+        *self = g;
+    }
 
     fn validate(&self, v:&usize){
         if v>= &self.V {
             panic!("Out of bounds!!")
         }
     }
-}
\ No newline at end of file
+}
+
+
+impl Clone for EdgeWeightedGraph {
+    /// Creates a deep copy of the graph
+    fn clone(&self) -> EdgeWeightedGraph {
+        let mut temp: Vec<Bag<Rc<Edge>>> = Vec::with_capacity(self.V);
+        for bag in &self.adj {
+            let mut b = Bag::<Rc<Edge>>::new();
+            for e in bag.iterator().collect::<Vec<_>>().into_iter().rev() {
+                b.add(Rc::new((**e).clone()));
+            }
+            temp.push(b);
+        }
+        EdgeWeightedGraph { V: self.V, E: self.E, adj: temp }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::{Edge, EdgeWeightedGraph};
+
+    #[test]
+    fn test_remove_edge(){
+        let mut g = EdgeWeightedGraph::new(3);
+        g.add_edge(Edge::new(0, 1, 5));
+        g.add_edge(Edge::new(1, 0, 7));
+        g.add_edge(Edge::new(1, 2, 1));
+
+        assert!(!g.remove_edge(&Edge::new(0, 1, 1)));
+        assert!(g.remove_edge(&Edge::new(0, 1, 7)));
+        assert!(g.has_edge(&0, &1));
+        assert_eq!(g.E, 2);
+        assert_eq!(g.adj(&0).next().unwrap().weight, 5);
+        assert!(g.remove_edge(&Edge::new(1, 0, 5)));
+        assert!(!g.has_edge(&1, &0));
+        assert_eq!(g.degree(&1), 1);
+    }
+
+    #[test]
+    fn test_add_and_remove_vertex(){
+        let mut g = EdgeWeightedGraph::new(3);
+        g.add_edge(Edge::new(0, 1, 5));
+        g.add_edge(Edge::new(1, 2, 1));
+        let v = g.add_vertex();
+        g.add_edge(Edge::new(2, v, 3));
+        g.add_edge(Edge::new(0, 2, 4));
+
+        g.remove_vertex(&1);
+        assert_eq!(g.V, 3);
+        assert_eq!(g.E, 2);
+        assert!(g.has_edge(&1, &2));
+        assert!(g.has_edge(&0, &1));
+        assert!(!g.has_edge(&0, &2));
+        assert_eq!(g.adj(&2).next().unwrap().weight, 3);
+    }
+}
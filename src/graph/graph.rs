@@ -40,6 +40,61 @@ impl Graph {
         self.adj[w].add(v);
     }
 
+    /// checks if there is an edge between v and w
+    pub fn has_edge(&self, v:usize, w:usize) -> bool {
+        self.validate(v);
+        self.validate(w);
+        self.adj[v].iterator().any(|x| *x == w)
+    }
+
+    /// removes one edge between v and w, returns false if there was no such edge
+    pub fn remove_edge(&mut self, v:usize, w:usize) -> bool {
+        self.validate(v);
+        self.validate(w);
+        if !self.adj[v].remove(&w) {
+            return false;
+        }
+        self.adj[w].remove(&v);
+        self.E -= 1;
+        true
+    }
+
+    /// adds a new isolated vertex and returns its index
+    pub fn add_vertex(&mut self) -> usize {
+        self.adj.push(Bag::<usize>::new());
+        self.V += 1;
+        self.V - 1
+    }
+
+    /// removes v and all edges incident to it.
+    /// The vertices after v are reindexed, so vertex w > v becomes w - 1.
+    /// This is *O(V+E)*, since every adjacency list has to be rewritten.
+    pub fn remove_vertex(&mut self, v:usize){
+        self.validate(v);
+        let mut removed = 0;
+        let mut self_loops = 0;
+        for w in self.adj[v].iterator() {
+            if *w == v {
+                self_loops += 1;
+            } else {
+                removed += 1;
+            }
+        }
+        self.E -= removed + self_loops / 2;
+
+        self.adj.remove(v);
+        self.V -= 1;
+        for bag in self.adj.iter_mut() {
+            let mut tmp = Bag::<usize>::new();
+            for w in bag.iterator() {
+                if *w != v {
+                    tmp.add(if *w > v { *w - 1 } else { *w });
+                }
+            }
+            *bag = tmp;
+        }
+    }
+
     fn validate(&self, p:usize){
         if p>= self.V {
             panic!("The index is out of bounds!")
@@ -85,4 +140,39 @@ mod tests {
         let mut it = g.adj_vertices(&0);
         assert_eq!(it.next(), None);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_remove_edge(){
+        let mut g = Graph::new(3);
+        g.add_edge(0, 1);
+        g.add_edge(0, 1);
+        g.add_edge(1, 2);
+        assert!(g.has_edge(1, 0));
+        assert!(g.remove_edge(1, 0));
+        assert!(g.has_edge(0, 1));
+        assert!(g.remove_edge(0, 1));
+        assert!(!g.has_edge(0, 1));
+        assert!(!g.remove_edge(0, 1));
+        assert_eq!(g.get_e(), 1);
+        assert_eq!(g.degree(0), 0);
+    }
+
+    #[test]
+    fn test_add_and_remove_vertex(){
+        let mut g = Graph::new(3);
+        g.add_edge(0, 1);
+        g.add_edge(1, 2);
+        g.add_edge(1, 1);
+        let v = g.add_vertex();
+        assert_eq!(v, 3);
+        g.add_edge(2, 3);
+        assert_eq!(g.get_e(), 4);
+
+        g.remove_vertex(1);
+        assert_eq!(g.get_v(), 3);
+        assert_eq!(g.get_e(), 1);
+        assert!(g.has_edge(1, 2));
+        assert!(!g.has_edge(0, 1));
+        assert_eq!(g.degree(0), 0);
+    }
+}
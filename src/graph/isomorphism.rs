@@ -0,0 +1,398 @@
+use super::graph::Graph;
+
+
+/// Isomorphism finds out if two graphs are the same graph with the vertices numbered differently, and computes a canonical form that can be hashed.
+///
+/// The functions are built from two parts.
+/// Color refinement (1-dimensional Weisfeiler-Lehman) gives every vertex a color from its degree and then repeatedly from the multiset of colors of its neighbours,
+/// until no color class is split anymore. Vertices that can be mapped to each other always end up with the same color.
+/// `find_isomorphism` is a VF2 style matcher, which extends a partial mapping one vertex at a time and only tries vertices of the same color
+/// whose edges to the already mapped vertices are the same.
+/// `canonical_form` individualizes the vertices of the first color class that is not a singleton one at a time and refines again,
+/// and the smallest adjacency matrix of all the discrete colorings found is the canonical form.
+///
+/// Both are exponential in the worst case, so they are meant for small graphs. Parallel edges and self loops are counted, so they have to match as well.
+///
+/// # Examples
+/// ```
+/// use itualgs_rs::graph::graph::Graph;
+/// use itualgs_rs::graph::isomorphism::{canonical_form, is_isomorphic};
+///
+/// let mut g = Graph::new(4);
+/// g.add_edge(0, 1);
+/// g.add_edge(1, 2);
+/// g.add_edge(2, 3);
+///
+/// let mut h = Graph::new(4);
+/// h.add_edge(3, 0);
+/// h.add_edge(0, 2);
+/// h.add_edge(2, 1);
+///
+/// assert!(is_isomorphic(&g, &h));
+/// assert_eq!(canonical_form(&g), canonical_form(&h));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CanonicalForm {
+    v: usize,
+    adj: Vec<usize>,
+}
+
+impl CanonicalForm {
+    /// The amount of vertices
+    pub fn get_v(&self) -> usize {
+        self.v
+    }
+
+    /// The amount of edges between v and w in the canonical labeling
+    pub fn edges_between(&self, v: usize, w: usize) -> usize {
+        self.adj[v * self.v + w]
+    }
+}
+
+/// Colors the vertices by color refinement. The colors are numbered from 0 and only depend on the structure of the graph,
+/// so two isomorphic graphs get the same colors on the vertices that are mapped to each other.
+pub fn color_refinement(g:&Graph) -> Vec<usize> {
+    let adj = adjacency(g);
+    refine(&[&adj], vec![vec![0; g.get_v()]]).pop().unwrap()
+}
+
+/// Checks if g and h are isomorphic
+pub fn is_isomorphic(g:&Graph, h:&Graph) -> bool {
+    find_isomorphism(g, h).is_some()
+}
+
+/// Finds a mapping from the vertices of g to the vertices of h, such that v and w are connected in g exactly when `map[v]` and `map[w]` are in h
+pub fn find_isomorphism(g:&Graph, h:&Graph) -> Option<Vec<usize>> {
+    if g.get_v() != h.get_v() || g.get_e() != h.get_e() {
+        return None;
+    }
+    let n = g.get_v();
+    let adj_g = adjacency(g);
+    let adj_h = adjacency(h);
+    let mut colors = refine(&[&adj_g, &adj_h], vec![vec![0; n], vec![0; n]]);
+    let colors_h = colors.pop().unwrap();
+    let colors_g = colors.pop().unwrap();
+
+    let mut count_g = colors_g.clone();
+    let mut count_h = colors_h.clone();
+    count_g.sort_unstable();
+    count_h.sort_unstable();
+    if count_g != count_h {
+        return None;
+    }
+
+    let matrix_g = matrix(&adj_g);
+    let matrix_h = matrix(&adj_h);
+    let order = match_order(&adj_g, &colors_g);
+    let mut state = MatchState {
+        n,
+        matrix_g: &matrix_g,
+        matrix_h: &matrix_h,
+        colors_g: &colors_g,
+        colors_h: &colors_h,
+        order: &order,
+        map: vec![usize::MAX; n],
+        used: vec![false; n],
+    };
+    if state.extend(0) {
+        Some(state.map)
+    } else {
+        None
+    }
+}
+
+/// Finds a canonical labeling of g, where `label[v]` is the new number of v.
+/// Two graphs are isomorphic exactly when relabeling them gives the same graph.
+pub fn canonical_labeling(g:&Graph) -> Vec<usize> {
+    canonical(g).1
+}
+
+/// Computes the canonical form of g, which is equal for two graphs exactly when they are isomorphic
+pub fn canonical_form(g:&Graph) -> CanonicalForm {
+    canonical(g).0
+}
+
+fn canonical(g:&Graph) -> (CanonicalForm, Vec<usize>) {
+    let n = g.get_v();
+    let adj = adjacency(g);
+    let m = matrix(&adj);
+    let mut best: Option<(Vec<usize>, Vec<usize>)> = None;
+    search_canonical(&adj, &m, vec![0; n], &mut best);
+    let (form, label) = best.unwrap_or((Vec::new(), Vec::new()));
+    (CanonicalForm { v: n, adj: form }, label)
+}
+
+fn search_canonical(adj: &[Vec<usize>], m: &[usize], colors: Vec<usize>, best: &mut Option<(Vec<usize>, Vec<usize>)>){
+    let n = adj.len();
+    let colors = refine(&[adj], vec![colors]).pop().unwrap();
+
+    let mut size = vec![0; n];
+    for c in &colors {
+        size[*c] += 1;
+    }
+    let cell = match (0..n).find(|c| size[*c] > 1) {
+        Some(c) => c,
+        None => {
+            // the coloring is discrete, so the colors are a labeling
+            let mut form = vec![0; n * n];
+            for v in 0..n {
+                for w in 0..n {
+                    form[colors[v] * n + colors[w]] = m[v * n + w];
+                }
+            }
+            if best.as_ref().is_none_or(|(b, _)| form < *b) {
+                *best = Some((form, colors));
+            }
+            return;
+        }
+    };
+
+    let mut tried: Vec<usize> = Vec::new();
+    for v in (0..n).filter(|v| colors[*v] == cell) {
+        // swapping two twins is an automorphism, so they give the same leaves
+        if tried.iter().any(|u| twins(m, n, *u, v)) {
+            continue;
+        }
+        tried.push(v);
+        let keys: Vec<(usize, bool)> = (0..n).map(|u| (colors[u], colors[u] == cell && u != v)).collect();
+        search_canonical(adj, m, rank(&keys), best);
+    }
+}
+
+fn twins(m: &[usize], n: usize, u: usize, v: usize) -> bool {
+    m[u * n + u] == m[v * n + v] && (0..n).all(|w| w == u || w == v || m[u * n + w] == m[v * n + w])
+}
+
+/// The neighbours of every vertex, with a neighbour repeated for every parallel edge
+fn adjacency(g:&Graph) -> Vec<Vec<usize>> {
+    (0..g.get_v()).map(|v| g.adj_vertices(&v).copied().collect()).collect()
+}
+
+/// The amount of edges between every pair of vertices, stored row by row
+fn matrix(adj: &[Vec<usize>]) -> Vec<usize> {
+    let n = adj.len();
+    let mut m = vec![0; n * n];
+    for (v, list) in adj.iter().enumerate() {
+        for w in list {
+            m[v * n + w] += 1;
+        }
+    }
+    m
+}
+
+/// Replaces every key by its rank among the distinct keys
+fn rank<K: Ord + Clone>(keys: &[K]) -> Vec<usize> {
+    let mut sorted = keys.to_vec();
+    sorted.sort();
+    sorted.dedup();
+    keys.iter().map(|k| sorted.binary_search(k).unwrap()).collect()
+}
+
+/// Refines the colorings of the graphs together, so the same color means the same thing in all of them
+fn refine(graphs: &[&[Vec<usize>]], mut colors: Vec<Vec<usize>>) -> Vec<Vec<usize>> {
+    let mut classes = usize::MAX;
+    loop {
+        let mut signatures = Vec::new();
+        for (adj, c) in graphs.iter().zip(&colors) {
+            for (v, list) in adj.iter().enumerate() {
+                let mut neighbours: Vec<usize> = list.iter().map(|w| c[*w]).collect();
+                neighbours.sort_unstable();
+                signatures.push((c[v], neighbours));
+            }
+        }
+        let ranks = rank(&signatures);
+        let count = ranks.iter().max().map_or(0, |m| m + 1);
+
+        let mut i = 0;
+        for c in colors.iter_mut() {
+            let len = c.len();
+            c.copy_from_slice(&ranks[i..i + len]);
+            i += len;
+        }
+        if count == classes {
+            return colors;
+        }
+        classes = count;
+    }
+}
+
+/// The order the vertices of g are matched in, every component is visited breath first starting from its rarest color
+fn match_order(adj: &[Vec<usize>], colors: &[usize]) -> Vec<usize> {
+    let n = adj.len();
+    let mut size = vec![0; n];
+    for c in colors {
+        size[*c] += 1;
+    }
+    let mut start: Vec<usize> = (0..n).collect();
+    start.sort_by_key(|v| (size[colors[*v]], colors[*v]));
+
+    let mut order = Vec::with_capacity(n);
+    let mut seen = vec![false; n];
+    for s in start {
+        if seen[s] {
+            continue;
+        }
+        seen[s] = true;
+        let mut i = order.len();
+        order.push(s);
+        while i < order.len() {
+            let v = order[i];
+            for w in &adj[v] {
+                if !seen[*w] {
+                    seen[*w] = true;
+                    order.push(*w);
+                }
+            }
+            i += 1;
+        }
+    }
+    order
+}
+
+struct MatchState<'a> {
+    n: usize,
+    matrix_g: &'a [usize],
+    matrix_h: &'a [usize],
+    colors_g: &'a [usize],
+    colors_h: &'a [usize],
+    order: &'a [usize],
+    map: Vec<usize>,
+    used: Vec<bool>,
+}
+
+impl MatchState<'_> {
+    fn extend(&mut self, depth: usize) -> bool {
+        if depth == self.n {
+            return true;
+        }
+        let v = self.order[depth];
+        for w in 0..self.n {
+            if self.used[w] || self.colors_g[v] != self.colors_h[w] || !self.feasible(depth, v, w) {
+                continue;
+            }
+            self.map[v] = w;
+            self.used[w] = true;
+            if self.extend(depth + 1) {
+                return true;
+            }
+            self.used[w] = false;
+            self.map[v] = usize::MAX;
+        }
+        false
+    }
+
+    /// v can be mapped to w if they have the same edges to themselves and to the vertices mapped so far
+    fn feasible(&self, depth: usize, v: usize, w: usize) -> bool {
+        let n = self.n;
+        self.matrix_g[v * n + v] == self.matrix_h[w * n + w]
+            && self.order[..depth].iter().all(|u| self.matrix_g[v * n + u] == self.matrix_h[w * n + self.map[*u]])
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use crate::graph::graph::Graph;
+    use crate::randomization::lcg_random::{lcg_generate_seeded, LcgRandom};
+
+    use super::{canonical_form, canonical_labeling, color_refinement, find_isomorphism, is_isomorphic};
+
+    fn build(v: usize, edges: &[(usize, usize)]) -> Graph {
+        let mut g = Graph::new(v);
+        for (a, b) in edges {
+            g.add_edge(*a, *b);
+        }
+        g
+    }
+
+    fn relabel(v: usize, edges: &[(usize, usize)], perm: &[usize]) -> Graph {
+        let moved: Vec<(usize, usize)> = edges.iter().map(|(a, b)| (perm[*a], perm[*b])).collect();
+        build(v, &moved)
+    }
+
+    #[test]
+    fn test_color_refinement(){
+        // a path 0-1-2-3-4, the ends, their neighbours and the middle get different colors
+        let g = build(5, &[(0, 1), (1, 2), (2, 3), (3, 4)]);
+        let colors = color_refinement(&g);
+        assert_eq!(colors[0], colors[4]);
+        assert_eq!(colors[1], colors[3]);
+        assert_ne!(colors[0], colors[1]);
+        assert_ne!(colors[1], colors[2]);
+    }
+
+    #[test]
+    fn test_regular_graphs_are_told_apart(){
+        // two triangles and a hexagon can not be told apart by color refinement
+        let triangles = build(6, &[(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3)]);
+        let hexagon = build(6, &[(0, 1), (1, 2), (2, 3), (3, 4), (4, 5), (5, 0)]);
+        assert_eq!(color_refinement(&triangles), color_refinement(&hexagon));
+        assert!(!is_isomorphic(&triangles, &hexagon));
+        assert_ne!(canonical_form(&triangles), canonical_form(&hexagon));
+    }
+
+    #[test]
+    fn test_random_relabeling(){
+        let v = 9;
+        let rand = lcg_generate_seeded(28, 31);
+        let edges: Vec<(usize, usize)> = rand.chunks(2).map(|c| (c[0] as usize % v, c[1] as usize % v)).collect();
+        let g = build(v, &edges);
+
+        let mut perm: Vec<usize> = (0..v).collect();
+        let mut random = LcgRandom::with_seed(13);
+        for i in (1..v).rev() {
+            perm.swap(i, random.uniform(i + 1));
+        }
+        let h = relabel(v, &edges, &perm);
+
+        let map = find_isomorphism(&g, &h).unwrap();
+        for (a, b) in &edges {
+            assert!(h.has_edge(map[*a], map[*b]));
+        }
+        assert_eq!(canonical_form(&g), canonical_form(&h));
+
+        let label = canonical_labeling(&g);
+        let form = canonical_form(&g);
+        for (a, b) in &edges {
+            assert!(form.edges_between(label[*a], label[*b]) > 0);
+        }
+
+        let mut other = edges.clone();
+        other[0] = ((other[0].0 + 1) % v, other[0].1);
+        let k = build(v, &other);
+        assert_eq!(is_isomorphic(&g, &k), canonical_form(&g) == canonical_form(&k));
+    }
+
+    #[test]
+    fn test_deduplicate_all_graphs_on_four_vertices(){
+        // there are 11 graphs on four vertices, up to isomorphism
+        let pairs = [(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)];
+        let mut forms = HashSet::new();
+        let mut graphs: Vec<Graph> = Vec::new();
+        for mask in 0..(1 << pairs.len()) {
+            let edges: Vec<(usize, usize)> = (0..pairs.len()).filter(|i| mask & (1 << i) != 0).map(|i| pairs[i]).collect();
+            let g = build(4, &edges);
+            if forms.insert(canonical_form(&g)) {
+                assert!(graphs.iter().all(|h| !is_isomorphic(h, &g)));
+                graphs.push(g);
+            }
+        }
+        assert_eq!(forms.len(), 11);
+    }
+
+    #[test]
+    fn test_complete_graph(){
+        let mut edges = Vec::new();
+        for v in 0..12 {
+            for w in v + 1..12 {
+                edges.push((v, w));
+            }
+        }
+        let g = build(12, &edges);
+        let perm: Vec<usize> = (0..12).rev().collect();
+        assert_eq!(canonical_form(&g), canonical_form(&relabel(12, &edges, &perm)));
+        assert!(is_isomorphic(&g, &relabel(12, &edges, &perm)));
+    }
+}
@@ -1,8 +1,8 @@
 use std::rc::Rc;
 
-use crate::fundamentals::{queue::Queue, uf::WeightedQuickUnionUF};
+use crate::fundamentals::{queue::Queue, uf::{UF, WeightedQuickUnionUF}};
 
-use super::{edge::Edge, edge_weighted_graph::EdgeWeightedGraph};
+use super::{certification::CertificationError, edge::Edge, edge_weighted_graph::EdgeWeightedGraph};
 
 
 /// Kruskals algorithm is created to find a minimum spanning tree over a weighted undirected graph. 
@@ -39,14 +39,21 @@ use super::{edge::Edge, edge_weighted_graph::EdgeWeightedGraph};
 pub struct KruskalMST {
     pub weight: u128,
     mst: Queue<Rc<Edge>>,
+    // the amount of vertices in the graph the forest was found in
+    v: usize,
 }
 
 impl KruskalMST {
     
     pub fn new(G: EdgeWeightedGraph) -> Self {
-        let mut kruskal = KruskalMST{weight: 0, mst: Queue::new()};
+        Self::with_uf::<WeightedQuickUnionUF>(G)
+    }
+
+    /// Finds the minimum spanning forest using the given union find implementation to detect cycles
+    pub fn with_uf<U: UF>(g: EdgeWeightedGraph) -> Self {
+        let mut kruskal = KruskalMST{weight: 0, mst: Queue::new(), v: g.V};
         let mut edges: Vec<&Rc<Edge>> = Vec::new();
-        let tmp = G.edges();
+        let tmp = g.edges();
         for e in tmp.iterator() {
             edges.push(e);
         }
@@ -54,11 +61,11 @@ impl KruskalMST {
 
         edges.sort_by(|a, b| a.cmp(b));
 
-        let mut uf = WeightedQuickUnionUF::new(G.V);
+        let mut uf = U::new(g.V);
 
         let mut i: usize = 0;
         loop {
-            if i >= G.E || kruskal.mst.size() > G.V - 1 {
+            if i >= g.E || kruskal.mst.size() > g.V - 1 {
                 break;
             }
             let edge = edges[i];
@@ -78,6 +85,59 @@ impl KruskalMST {
     pub fn edges(&mut self) -> &mut Queue<Rc<Edge>>{
         &mut self.mst
     }
+
+    /// Certifies that the edges form a minimum spanning forest of the graph g.
+    /// The weight has to be the sum of the edges, the edges may not form a cycle, every edge of g has to be inside a tree,
+    /// and by the cut optimality condition no edge of g crossing the cut given by removing a tree edge may be lighter than that tree edge.
+    /// Is *O(EV)*. A graph of another size than the one the forest was found in is reported as an error.
+    pub fn check(&self, g: &EdgeWeightedGraph) -> Result<(), CertificationError> {
+        if g.V != self.v {
+            return Err(CertificationError::GraphSizeMismatch { expected: self.v, actual: g.V });
+        }
+        let total: u128 = self.mst.iterator().map(|e| e.weight).sum();
+        if total != self.weight {
+            return Err(CertificationError::WeightMismatch { expected: total, actual: self.weight });
+        }
+
+        let mut uf = WeightedQuickUnionUF::new(g.V);
+        for e in self.mst.iterator() {
+            let v = *e.either();
+            let w = *e.other(v);
+            if uf.connected(v, w) {
+                return Err(CertificationError::NotAForest { v, w, weight: e.weight });
+            }
+            uf.union(v, w);
+        }
+        for e in g.edges().iterator() {
+            let v = *e.either();
+            let w = *e.other(v);
+            if !uf.connected(v, w) {
+                return Err(CertificationError::NotSpanning { v, w, weight: e.weight });
+            }
+        }
+
+        for (i, tree_edge) in self.mst.iterator().enumerate() {
+            // the components left when the tree edge is removed
+            let mut uf = WeightedQuickUnionUF::new(g.V);
+            for (j, f) in self.mst.iterator().enumerate() {
+                if i != j {
+                    uf.union(*f.either(), *f.other(*f.either()));
+                }
+            }
+            for e in g.edges().iterator() {
+                let v = *e.either();
+                let w = *e.other(v);
+                if !uf.connected(v, w) && e.weight < tree_edge.weight {
+                    let x = *tree_edge.either();
+                    return Err(CertificationError::CutOptimality {
+                        v, w, weight: e.weight,
+                        x, y: *tree_edge.other(x), tree_weight: tree_edge.weight,
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -106,4 +166,85 @@ mod tests {
         assert_eq!(w1.weight, 2);
         assert_eq!(w2.weight, 10);
     }
+
+    fn tiny_graph() -> EdgeWeightedGraph {
+        let mut g = EdgeWeightedGraph::new(5);
+        let list = vec![
+            Edge::new(0, 1, 10),
+            Edge::new(2, 1, 2),
+            Edge::new(2, 0, 20),
+            Edge::new(3, 4, 5)];
+        for ele in list {
+            g.add_edge(ele);
+        }
+        g
+    }
+
+    #[test]
+    fn test_check(){
+        let k = KruskalMST::new(tiny_graph());
+        assert_eq!(k.check(&tiny_graph()), Ok(()));
+    }
+
+    #[test]
+    fn test_check_finds_errors(){
+        use std::rc::Rc;
+        use crate::fundamentals::queue::Queue;
+        use crate::graph::certification::CertificationError;
+
+        let mut k = KruskalMST::new(tiny_graph());
+        k.weight += 1;
+        assert_eq!(k.check(&tiny_graph()), Err(CertificationError::WeightMismatch { expected: 17, actual: 18 }));
+
+        let mut k = KruskalMST::new(tiny_graph());
+        k.mst = Queue::new();
+        k.mst.enqueue(Rc::new(Edge::new(0, 1, 10)));
+        k.mst.enqueue(Rc::new(Edge::new(2, 0, 20)));
+        k.mst.enqueue(Rc::new(Edge::new(3, 4, 5)));
+        k.weight = 35;
+        assert_eq!(k.check(&tiny_graph()), Err(CertificationError::CutOptimality {
+            v: 2, w: 1, weight: 2, x: 0, y: 1, tree_weight: 10,
+        }));
+
+        k.mst.dequeue();
+        k.weight = 25;
+        assert!(matches!(k.check(&tiny_graph()), Err(CertificationError::NotSpanning { .. })));
+
+        let mut k = KruskalMST::new(tiny_graph());
+        k.mst.enqueue(Rc::new(Edge::new(2, 0, 20)));
+        k.weight += 20;
+        assert_eq!(k.check(&tiny_graph()), Err(CertificationError::NotAForest { v: 2, w: 0, weight: 20 }));
+    }
+
+    #[test]
+    fn test_check_finds_size_mismatch(){
+        use crate::graph::certification::CertificationError;
+
+        let k = KruskalMST::new(tiny_graph());
+        assert_eq!(k.check(&EdgeWeightedGraph::new(3)), Err(CertificationError::GraphSizeMismatch { expected: 5, actual: 3 }));
+        assert_eq!(k.check(&EdgeWeightedGraph::new(6)), Err(CertificationError::GraphSizeMismatch { expected: 5, actual: 6 }));
+    }
+
+    #[test]
+    fn test_with_uf(){
+        use crate::fundamentals::uf::{QuickFindUF, QuickUnionUF, RankPathCompressionUF};
+        use crate::randomization::lcg_random::lcg_generate_seeded;
+
+        let v = 20;
+        let rand = lcg_generate_seeded(150, 37);
+        let mut g = EdgeWeightedGraph::new(v);
+        for c in rand.chunks(3) {
+            g.add_edge(Edge::new(c[0] as usize % v, c[1] as usize % v, (c[2] % 100) as u128));
+        }
+
+        let expected = KruskalMST::new(g.clone()).weight;
+        for k in [
+            KruskalMST::with_uf::<QuickFindUF>(g.clone()),
+            KruskalMST::with_uf::<QuickUnionUF>(g.clone()),
+            KruskalMST::with_uf::<RankPathCompressionUF>(g.clone()),
+        ] {
+            assert_eq!(k.weight, expected);
+            assert_eq!(k.check(&g), Ok(()));
+        }
+    }
 }
@@ -0,0 +1,297 @@
+use crate::{fundamentals::uf::WeightedQuickUnionUF, randomization::lcg_random::LcgRandom};
+
+use super::edge_weighted_graph::EdgeWeightedGraph;
+
+
+/// The Stoer-Wagner algorithm finds a global minimum cut of an undirected edge weighted graph,
+/// which is a split of the vertices into two non empty sides, such that the total weight of the edges crossing between them is as small as possible.
+///
+/// It runs *V-1* phases. Every phase adds the vertices one at a time, always the one most tightly connected to the ones already added,
+/// and the cut between the last vertex and the rest is a candidate. The last two vertices are then merged.
+/// With an adjacency matrix this is *O(V^3)*. The result is always a minimum cut.
+///
+/// # Examples
+/// ```
+/// use itualgs_rs::graph::min_cut::StoerWagnerMinCut;
+/// use itualgs_rs::graph::edge::Edge;
+/// use itualgs_rs::graph::edge_weighted_graph::EdgeWeightedGraph;
+///
+/// // two triangles joined by a light edge
+/// let mut g = EdgeWeightedGraph::new(6);
+/// let list = vec![
+///     Edge::new(0, 1, 5), Edge::new(1, 2, 5), Edge::new(2, 0, 5),
+///     Edge::new(3, 4, 5), Edge::new(4, 5, 5), Edge::new(5, 3, 5),
+///     Edge::new(2, 3, 1)];
+/// for ele in list {
+///     g.add_edge(ele);
+/// }
+/// let min_cut = StoerWagnerMinCut::new(&g);
+/// assert_eq!(min_cut.weight(), 1);
+/// assert_eq!(min_cut.cut(0), min_cut.cut(2));
+/// assert_ne!(min_cut.cut(2), min_cut.cut(3));
+/// ```
+pub struct StoerWagnerMinCut {
+    weight: u128,
+    cut: Vec<bool>,
+}
+
+impl StoerWagnerMinCut {
+
+    /// Finds a minimum cut of g, which needs at least two vertices
+    pub fn new(g: &EdgeWeightedGraph) -> Self {
+        let n = g.V;
+        validate(n);
+        let mut w = weight_matrix(g);
+        // the original vertices merged into every vertex
+        let mut groups: Vec<Vec<usize>> = (0..n).map(|v| vec![v]).collect();
+        let mut active: Vec<usize> = (0..n).collect();
+
+        let mut best = StoerWagnerMinCut { weight: u128::MAX, cut: vec![false; n] };
+        while active.len() > 1 {
+            // maximum adjacency ordering of the active vertices
+            let mut added = vec![false; n];
+            let mut connection = vec![0u128; n];
+            let mut prev = active[0];
+            let mut last = active[0];
+            for _ in 0..active.len() {
+                let next = *active.iter()
+                    .filter(|v| !added[**v])
+                    .max_by_key(|v| connection[**v])
+                    .unwrap();
+                added[next] = true;
+                prev = last;
+                last = next;
+                for v in &active {
+                    if !added[*v] {
+                        connection[*v] += w[next][*v];
+                    }
+                }
+            }
+
+            // the cut of the phase separates the last vertex from the rest
+            if connection[last] < best.weight {
+                best.weight = connection[last];
+                best.cut = vec![false; n];
+                for v in &groups[last] {
+                    best.cut[*v] = true;
+                }
+            }
+
+            // merge the last vertex into the one before it
+            let moved = std::mem::take(&mut groups[last]);
+            groups[prev].extend(moved);
+            let mut merged: Vec<u128> = w[prev].iter().zip(&w[last]).map(|(a, b)| a + b).collect();
+            merged[prev] = 0;
+            for (row, weight) in w.iter_mut().zip(&merged) {
+                row[prev] = *weight;
+            }
+            w[prev] = merged;
+            active.retain(|v| *v != last);
+        }
+        best
+    }
+
+    /// The total weight of the edges crossing the cut
+    pub fn weight(&self) -> u128 {
+        self.weight
+    }
+
+    /// Which side of the cut v is on
+    pub fn cut(&self, v: usize) -> bool {
+        self.cut[v]
+    }
+}
+
+
+/// Kargers algorithm is a randomized way of finding a global minimum cut of an undirected edge weighted graph.
+/// A trial contracts random edges, picked with a probability proportional to their weight, until only two super vertices are left,
+/// which gives a cut. One trial finds a minimum cut with probability at least *2/(V(V-1))*, so the algorithm runs a number of trials and keeps the best cut.
+/// After *t* trials the probability of missing every minimum cut is at most *(1 - 2/(V(V-1)))^t*, and `trials_for` gives the amount of trials needed for a given failure probability.
+///
+/// A trial uses a `WeightedQuickUnionUF` to keep track of the contracted vertices and is *O(VE)*.
+/// The random choices come from an `LcgRandom`, which can be seeded to make the result reproducible.
+///
+/// # Examples
+/// ```
+/// use itualgs_rs::graph::min_cut::KargerMinCut;
+/// use itualgs_rs::graph::edge::Edge;
+/// use itualgs_rs::graph::edge_weighted_graph::EdgeWeightedGraph;
+///
+/// let mut g = EdgeWeightedGraph::new(4);
+/// let list = vec![
+///     Edge::new(0, 1, 4), Edge::new(1, 2, 1), Edge::new(2, 3, 4), Edge::new(3, 0, 1)];
+/// for ele in list {
+///     g.add_edge(ele);
+/// }
+/// let trials = KargerMinCut::trials_for(g.V, 0.001);
+/// let min_cut = KargerMinCut::with_seed(&g, trials, 42);
+/// assert_eq!(min_cut.weight(), 2);
+/// assert_eq!(min_cut.cut(0), min_cut.cut(1));
+/// ```
+pub struct KargerMinCut {
+    weight: u128,
+    cut: Vec<bool>,
+}
+
+impl KargerMinCut {
+
+    /// Runs the given amount of trials, seeded from the current time
+    pub fn new(g: &EdgeWeightedGraph, trials: usize) -> Self {
+        Self::run(g, trials, &mut LcgRandom::new())
+    }
+
+    /// Runs the given amount of trials, with a fixed seed
+    pub fn with_seed(g: &EdgeWeightedGraph, trials: usize, seed: u64) -> Self {
+        Self::run(g, trials, &mut LcgRandom::with_seed(seed))
+    }
+
+    /// The amount of trials needed so a minimum cut is missed with at most the given probability
+    pub fn trials_for(v: usize, failure: f64) -> usize {
+        let pairs = (v * v.saturating_sub(1) / 2).max(1) as f64;
+        ((1.0 / failure).ln() * pairs).ceil().max(1.0) as usize
+    }
+
+    fn run(g: &EdgeWeightedGraph, trials: usize, random: &mut LcgRandom) -> Self {
+        let n = g.V;
+        validate(n);
+        let edges: Vec<(usize, usize, u128)> = g.edges().iterator()
+            .map(|e| (*e.either(), *e.other(*e.either()), e.weight))
+            .filter(|(v, w, _)| v != w)
+            .collect();
+
+        let mut best = KargerMinCut { weight: u128::MAX, cut: vec![false; n] };
+        for _ in 0..trials.max(1) {
+            let mut uf = WeightedQuickUnionUF::new(n);
+            while uf.count() > 2 {
+                let crossing: Vec<&(usize, usize, u128)> = edges.iter().filter(|(v, w, _)| !uf.connected(*v, *w)).collect();
+                if crossing.is_empty() {
+                    // the graph is disconnected, any two components can be merged
+                    let v = (1..n).find(|v| !uf.connected(0, *v)).unwrap();
+                    let w = (1..n).find(|w| !uf.connected(0, *w) && !uf.connected(v, *w)).unwrap_or(0);
+                    uf.union(v, w);
+                    continue;
+                }
+                let total: u128 = crossing.iter().map(|(_, _, weight)| *weight).sum();
+                let (v, w, _) = if total == 0 {
+                    crossing[random.uniform(crossing.len())]
+                } else {
+                    let mut r = ((random.next_u32() as u128) << 31 | random.next_u32() as u128) % total;
+                    let mut i = 0;
+                    while r >= crossing[i].2 {
+                        r -= crossing[i].2;
+                        i += 1;
+                    }
+                    crossing[i]
+                };
+                uf.union(*v, *w);
+            }
+
+            let weight = edges.iter().filter(|(v, w, _)| !uf.connected(*v, *w)).map(|(_, _, weight)| *weight).sum();
+            if weight < best.weight {
+                best.weight = weight;
+                best.cut = (0..n).map(|v| uf.connected(0, v)).collect();
+            }
+        }
+        best
+    }
+
+    /// The total weight of the edges crossing the best cut found
+    pub fn weight(&self) -> u128 {
+        self.weight
+    }
+
+    /// Which side of the cut v is on
+    pub fn cut(&self, v: usize) -> bool {
+        self.cut[v]
+    }
+}
+
+fn validate(v: usize){
+    if v < 2 {
+        panic!("A cut needs at least two vertices!")
+    }
+}
+
+/// The total weight of the edges between every pair of vertices, self loops never cross a cut so they are left out
+fn weight_matrix(g: &EdgeWeightedGraph) -> Vec<Vec<u128>> {
+    let mut w = vec![vec![0u128; g.V]; g.V];
+    for e in g.edges().iterator() {
+        let v = *e.either();
+        let u = *e.other(v);
+        if v != u {
+            w[v][u] += e.weight;
+            w[u][v] += e.weight;
+        }
+    }
+    w
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::graph::{edge::Edge, edge_weighted_graph::EdgeWeightedGraph};
+    use crate::randomization::lcg_random::lcg_generate_seeded;
+
+    use super::{KargerMinCut, StoerWagnerMinCut};
+
+    fn cut_weight(g: &EdgeWeightedGraph, side: impl Fn(usize) -> bool) -> u128 {
+        g.edges().iterator()
+            .filter(|e| side(*e.either()) != side(*e.other(*e.either())))
+            .map(|e| e.weight)
+            .sum()
+    }
+
+    fn brute_force(g: &EdgeWeightedGraph) -> u128 {
+        (1..(1u32 << (g.V - 1))).map(|mask| cut_weight(g, |v| v > 0 && mask & (1 << (v - 1)) != 0)).min().unwrap()
+    }
+
+    #[test]
+    fn test_same_as_brute_force(){
+        let v = 9;
+        let rand = lcg_generate_seeded(90, 33);
+        let mut g = EdgeWeightedGraph::new(v);
+        for c in rand.chunks(3) {
+            g.add_edge(Edge::new(c[0] as usize % v, c[1] as usize % v, (c[2] % 10) as u128));
+        }
+        let expected = brute_force(&g);
+
+        let sw = StoerWagnerMinCut::new(&g);
+        assert_eq!(sw.weight(), expected);
+        assert_eq!(cut_weight(&g, |v| sw.cut(v)), expected);
+        assert!((0..v).any(|v| sw.cut(v)) && (0..v).any(|v| !sw.cut(v)));
+
+        let karger = KargerMinCut::with_seed(&g, KargerMinCut::trials_for(v, 0.0001), 1234);
+        assert_eq!(karger.weight(), expected);
+        assert_eq!(cut_weight(&g, |v| karger.cut(v)), expected);
+        assert!((0..v).any(|v| karger.cut(v)) && (0..v).any(|v| !karger.cut(v)));
+    }
+
+    #[test]
+    fn test_disconnected(){
+        let mut g = EdgeWeightedGraph::new(5);
+        g.add_edge(Edge::new(0, 1, 3));
+        g.add_edge(Edge::new(2, 3, 3));
+        g.add_edge(Edge::new(3, 4, 3));
+        assert_eq!(StoerWagnerMinCut::new(&g).weight(), 0);
+        let karger = KargerMinCut::with_seed(&g, 1, 7);
+        assert_eq!(karger.weight(), 0);
+        assert_eq!(karger.cut(0), karger.cut(1));
+    }
+
+    #[test]
+    fn test_karger_single_trial_is_a_cut(){
+        let mut g = EdgeWeightedGraph::new(4);
+        for (v, w) in [(0, 1), (1, 2), (2, 3), (3, 0), (0, 2)] {
+            g.add_edge(Edge::new(v, w, 1));
+        }
+        let karger = KargerMinCut::new(&g, 1);
+        assert!(karger.weight() >= 2);
+        assert_eq!(cut_weight(&g, |v| karger.cut(v)), karger.weight());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_one_vertex(){
+        StoerWagnerMinCut::new(&EdgeWeightedGraph::new(1));
+    }
+}
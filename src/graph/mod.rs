@@ -6,4 +6,17 @@ pub mod edge;
 pub mod kruskal_mst;
 pub mod edge_weighted_graph;
 pub mod dijkstra_sp;
-
+pub mod a_star;
+pub mod bidirectional_bfp;
+pub mod bidirectional_dijkstra_sp;
+pub mod yen_ksp;
+pub mod digraph;
+pub mod directed_dfs;
+pub mod transitive_closure;
+pub mod isomorphism;
+pub mod coloring;
+pub mod min_cut;
+pub mod directed_bfp;
+pub mod centrality;
+pub mod certification;
+pub mod dynamic_connectivity;
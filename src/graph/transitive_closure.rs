@@ -0,0 +1,71 @@
+use super::{digraph::Digraph, directed_dfs::DirectedDFS};
+
+
+/// The transitive closure of a digraph answers if there is a directed path from v to w.
+/// It is computed by running a `DirectedDFS` from every vertex, so `new` takes *O(V(V+E))* time and *O(V^2)* space.
+/// After that `reachable` is constant time.
+///
+/// # Examples
+/// ```
+/// use itualgs_rs::graph::transitive_closure::TransitiveClosure;
+/// use itualgs_rs::graph::digraph::Digraph;
+///
+/// let mut g = Digraph::new(3);
+/// g.add_edge(0, 1);
+/// g.add_edge(1, 2);
+/// let tc = TransitiveClosure::new(&g);
+/// assert!(tc.reachable(0, 2));
+/// assert!(!tc.reachable(2, 0));
+/// ```
+pub struct TransitiveClosure {
+    tc: Vec<DirectedDFS>,
+}
+
+impl TransitiveClosure {
+
+    /// computes the transitive closure of g
+    pub fn new(g:&Digraph) -> TransitiveClosure {
+        let tc = (0..g.get_v()).map(|v| DirectedDFS::new(g, v)).collect();
+        TransitiveClosure { tc }
+    }
+
+    /// is there a directed path from v to w, every vertex can reach itself
+    pub fn reachable(&self, v: usize, w: usize) -> bool {
+        self.validate(v);
+        self.validate(w);
+        self.tc[v].marked[w]
+    }
+
+    fn validate(&self, p: usize){
+        if p >= self.tc.len() {
+            panic!("The index is out of bounds!")
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::graph::digraph::Digraph;
+
+    use super::TransitiveClosure;
+
+    #[test]
+    fn test_cycle_and_tail(){
+        let mut g = Digraph::new(5);
+        g.add_edge(0, 1);
+        g.add_edge(1, 2);
+        g.add_edge(2, 0);
+        g.add_edge(2, 3);
+        let tc = TransitiveClosure::new(&g);
+        for v in 0..3 {
+            for w in 0..4 {
+                assert!(tc.reachable(v, w));
+            }
+            assert!(!tc.reachable(v, 4));
+            assert!(!tc.reachable(3, v));
+        }
+        assert!(tc.reachable(4, 4));
+        assert!(!tc.reachable(3, 4));
+    }
+}
@@ -0,0 +1,261 @@
+use crate::fundamentals::stack::Stack;
+
+use super::{dijkstra_sp::DijkstraSP, edge::Edge, edge_weighted_graph::EdgeWeightedGraph};
+
+
+/// Yens algorithm finds the k shortest simple paths between two vertices s and t in an edge weighted graph.
+/// The first path is the one `DijkstraSP` finds. Every following path is found by taking each vertex of the previous path as a spur vertex,
+/// removing the edges that would recreate an already found path together with the vertices before the spur vertex,
+/// and running `DijkstraSP` from the spur vertex in what is left of the graph. The cheapest of all these candidates is the next path.
+///
+/// The paths are simple, which means that no vertex is visited twice, and they are ordered by increasing weight.
+/// The running time is *O(K V (E log V))*, since every path gives up to *V* calls to Dijkstra.
+///
+/// # Examples
+/// ```
+/// use itualgs_rs::graph::yen_ksp::YenKSP;
+/// use itualgs_rs::graph::edge::Edge;
+/// use itualgs_rs::graph::edge_weighted_graph::EdgeWeightedGraph;
+///
+/// let mut g = EdgeWeightedGraph::new(4);
+/// let list = vec![
+///     Edge::new(0, 1, 1),
+///     Edge::new(1, 3, 1),
+///     Edge::new(0, 2, 2),
+///     Edge::new(2, 3, 2),
+///     Edge::new(1, 2, 1)];
+///
+/// for ele in list {
+///     g.add_edge(ele);
+/// }
+/// let ksp = YenKSP::new(&g, 0, 3, 3);
+/// assert_eq!(ksp.count(), 3);
+/// assert_eq!(ksp.weight_of(0), 2);
+/// assert_eq!(ksp.weight_of(1), 4);
+/// assert_eq!(ksp.weight_of(2), 4);
+///
+/// let mut path = ksp.path(0).unwrap();
+/// assert_eq!(*path.pop().unwrap().other(0), 1);
+/// ```
+pub struct YenKSP {
+    paths: Vec<Vec<Edge>>,
+    weights: Vec<u128>,
+}
+
+impl YenKSP {
+
+    /// Finds up to k shortest simple paths from s to t
+    pub fn new(g: &EdgeWeightedGraph, s: usize, t: usize, k: usize) -> Self {
+        let mut ksp = YenKSP { paths: Vec::new(), weights: Vec::new() };
+        if k == 0 {
+            return ksp;
+        }
+        match Self::shortest(g.clone(), s, t) {
+            Some(p) => ksp.push(p),
+            None => return ksp,
+        }
+
+        let mut candidates: Vec<(u128, Vec<Edge>)> = Vec::new();
+        while ksp.count() < k {
+            let prev = ksp.paths.last().unwrap();
+            let prev_vertices = Self::vertices(prev, s);
+
+            for j in 0..prev.len() {
+                let spur = prev_vertices[j];
+                let root = &prev[..j];
+                let mut h = g.clone();
+
+                // remove the next edge of every found path that starts with the same root,
+                // together with its parallel copies, since a path through a copy is the same path
+                for p in &ksp.paths {
+                    if p.len() > j && Self::same_edges(&p[..j], root) {
+                        while h.remove_edge(&p[j]) {}
+                    }
+                }
+                // remove the root vertices, so the spur path can not go back through them
+                for v in &prev_vertices[..j] {
+                    let incident: Vec<Edge> = h.adj(v).map(|e| (**e).clone()).collect();
+                    for e in incident {
+                        h.remove_edge(&e);
+                    }
+                }
+
+                if let Some(mut spur_path) = Self::shortest(h, spur, t) {
+                    let mut path = root.to_vec();
+                    path.append(&mut spur_path);
+                    let weight = Self::weight(&path);
+                    let known = candidates.iter().any(|(_, c)| Self::same_edges(c, &path))
+                        || ksp.paths.iter().any(|c| Self::same_edges(c, &path));
+                    if !known {
+                        candidates.push((weight, path));
+                    }
+                }
+            }
+
+            if candidates.is_empty() {
+                break;
+            }
+            let mut best = 0;
+            for i in 1..candidates.len() {
+                let (w, p) = &candidates[i];
+                let (bw, bp) = &candidates[best];
+                if w < bw || (w == bw && p.len() < bp.len()) {
+                    best = i;
+                }
+            }
+            let (_, path) = candidates.swap_remove(best);
+            ksp.push(path);
+        }
+        ksp
+    }
+
+    fn push(&mut self, path: Vec<Edge>){
+        self.weights.push(Self::weight(&path));
+        self.paths.push(path);
+    }
+
+    /// The shortest path from s to t as a list of edges, with the edge leaving s first
+    fn shortest(g: EdgeWeightedGraph, s: usize, t: usize) -> Option<Vec<Edge>> {
+        let sp = DijkstraSP::new(g, s);
+        let mut stack = sp.path_to(&t)?;
+        let mut path = Vec::with_capacity(stack.size());
+        while let Some(e) = stack.pop() {
+            path.push(e);
+        }
+        Some(path)
+    }
+
+    fn weight(path: &[Edge]) -> u128 {
+        path.iter().map(|e| e.weight).sum()
+    }
+
+    /// The vertices visited by a path starting in s
+    fn vertices(path: &[Edge], s: usize) -> Vec<usize> {
+        let mut vertices = vec![s];
+        let mut x = s;
+        for e in path {
+            x = *e.other(x);
+            vertices.push(x);
+        }
+        vertices
+    }
+
+    /// Edges are compared by their endpoints and weight, so parallel edges of the same weight count as the same edge,
+    /// and paths that only differ in which of them they use count as the same path
+    fn same_edges(a: &[Edge], b: &[Edge]) -> bool {
+        a.len() == b.len() && a.iter().zip(b).all(|(e, f)| {
+            let (v, w) = (*e.either(), *e.other(*e.either()));
+            let (x, y) = (*f.either(), *f.other(*f.either()));
+            e.weight == f.weight && ((v == x && w == y) || (v == y && w == x))
+        })
+    }
+
+    /// The amount of paths found, which is less than k if there are not k simple paths from s to t
+    pub fn count(&self) -> usize {
+        self.paths.len()
+    }
+
+    /// The weight of the i'th shortest path
+    pub fn weight_of(&self, i: usize) -> u128 {
+        self.weights[i]
+    }
+
+    /// The edges of the i'th shortest path, with the edge leaving s on the top of the stack
+    pub fn path(&self, i: usize) -> Option<Stack<Edge>> {
+        let path = self.paths.get(i)?;
+        let mut s = Stack::<Edge>::new();
+        for e in path.iter().rev() {
+            s.push(e.clone());
+        }
+        Some(s)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::graph::{edge::Edge, edge_weighted_graph::EdgeWeightedGraph};
+    use crate::randomization::lcg_random::lcg_generate_seeded;
+
+    use super::YenKSP;
+
+    /// Finds the weights of every simple path from v to t
+    fn all_simple_paths(g: &EdgeWeightedGraph, v: usize, t: usize, on_path: &mut Vec<bool>, weight: u128, res: &mut Vec<u128>){
+        if v == t {
+            res.push(weight);
+            return;
+        }
+        on_path[v] = true;
+        for e in g.adj(&v) {
+            let w = *e.other(v);
+            if !on_path[w] {
+                all_simple_paths(g, w, t, on_path, weight + e.weight, res);
+            }
+        }
+        on_path[v] = false;
+    }
+
+    #[test]
+    fn test_fewer_than_k_paths(){
+        let mut g = EdgeWeightedGraph::new(3);
+        g.add_edge(Edge::new(0, 1, 1));
+        g.add_edge(Edge::new(1, 2, 1));
+        let ksp = YenKSP::new(&g, 0, 2, 5);
+        assert_eq!(ksp.count(), 1);
+        assert_eq!(ksp.weight_of(0), 2);
+        assert!(ksp.path(1).is_none());
+
+        let none = YenKSP::new(&EdgeWeightedGraph::new(2), 0, 1, 3);
+        assert_eq!(none.count(), 0);
+    }
+
+    #[test]
+    fn test_parallel_edges(){
+        let mut g = EdgeWeightedGraph::new(3);
+        g.add_edge(Edge::new(0, 1, 1));
+        g.add_edge(Edge::new(0, 1, 1));
+        g.add_edge(Edge::new(1, 2, 1));
+        g.add_edge(Edge::new(0, 2, 5));
+        // the path through the second copy of 0-1 is the same path, so it must not hide 0-2
+        let ksp = YenKSP::new(&g, 0, 2, 3);
+        assert_eq!(ksp.count(), 2);
+        assert_eq!(ksp.weight_of(0), 2);
+        assert_eq!(ksp.weight_of(1), 5);
+    }
+
+    #[test]
+    fn test_same_as_brute_force(){
+        let v = 8;
+        let rand = lcg_generate_seeded(60, 29);
+        let mut g = EdgeWeightedGraph::new(v);
+        for c in rand.chunks(3) {
+            let (a, b) = (c[0] as usize % v, c[1] as usize % v);
+            if a != b && !g.has_edge(&a, &b) {
+                g.add_edge(Edge::new(a, b, 1 + (c[2] % 20) as u128));
+            }
+        }
+
+        let (s, t, k) = (0, v - 1, 10);
+        let mut expected = Vec::new();
+        all_simple_paths(&g, s, t, &mut vec![false; v], 0, &mut expected);
+        expected.sort();
+        expected.truncate(k);
+
+        let ksp = YenKSP::new(&g, s, t, k);
+        assert_eq!(ksp.count(), expected.len());
+        for (i, weight) in expected.iter().enumerate() {
+            assert_eq!(ksp.weight_of(i), *weight);
+
+            let mut path = ksp.path(i).unwrap();
+            let mut seen = vec![false; v];
+            let mut x = s;
+            seen[x] = true;
+            while let Some(e) = path.pop() {
+                x = *e.other(x);
+                assert!(!seen[x]);
+                seen[x] = true;
+            }
+            assert_eq!(x, t);
+        }
+    }
+}
@@ -21,24 +21,74 @@ use std::time::{SystemTime, UNIX_EPOCH};
 /// assert!(random_vector.len() == 200);
 /// ``` 
 pub fn lcg_generate(size: usize) -> Vec<u32> {
-    let mut random_list = Vec::new();
-
-    // Seed the linear congruence generator with
-    // the current system time. This is not
-    // cryptographically secure. However we
-    // don't need this.
-    let mut x = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .unwrap()
-        .subsec_nanos() as u64;
-
-    // Run the linear congruence generator
-    for _ in 0..size {
-        x = (1103515245 * x + 12345) % 2147483648 as u64;
-        random_list.push(x as u32);
+    let mut random = LcgRandom::new();
+    (0..size).map(|_| random.next_u32()).collect()
+}
+
+/// Generates the same list as `lcg_generate`, but from a fixed seed, so the list is the same every time.
+pub fn lcg_generate_seeded(size: usize, seed: u64) -> Vec<u32> {
+    let mut random = LcgRandom::with_seed(seed);
+    (0..size).map(|_| random.next_u32()).collect()
+}
+
+/// LcgRandom is the linear congruence generator behind `lcg_generate`, which keeps its state
+/// so the numbers can be drawn one at a time. This is what algorithms that need a random choice
+/// in every step, like Kargers algorithm or a treap, use.
+///
+/// # Examples
+/// ```
+/// use itualgs_rs::randomization::lcg_random::LcgRandom;
+///
+/// let mut a = LcgRandom::with_seed(42);
+/// let mut b = LcgRandom::with_seed(42);
+/// assert_eq!(a.next_u32(), b.next_u32());
+/// assert!(a.uniform(10) < 10);
+/// ```
+pub struct LcgRandom {
+    x: u64,
+}
+
+impl LcgRandom {
+    /// Seeds the generator with the current system time
+    pub fn new() -> LcgRandom {
+        // Seed the linear congruence generator with
+        // the current system time. This is not
+        // cryptographically secure. However we
+        // don't need this.
+        let seed = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .subsec_nanos() as u64;
+        LcgRandom::with_seed(seed)
+    }
+
+    /// Seeds the generator with a fixed seed
+    pub fn with_seed(seed: u64) -> LcgRandom {
+        LcgRandom { x: seed % 2147483648 }
+    }
+
+    /// The next number, which is below 2^31
+    pub fn next_u32(&mut self) -> u32 {
+        self.x = (1103515245 * self.x + 12345) % 2147483648;
+        self.x as u32
+    }
+
+    /// A number between 0 (inclusive) and n (exclusive).
+    /// The high bits of the generator are used, since the low bits of an LCG have short periods.
+    pub fn uniform(&mut self, n: usize) -> usize {
+        ((self.next_u32() as u64 * n as u64) >> 31) as usize
+    }
+
+    /// A number between 0 (inclusive) and 1 (exclusive)
+    pub fn next_f64(&mut self) -> f64 {
+        self.next_u32() as f64 / 2147483648.0
+    }
+}
+
+impl Default for LcgRandom {
+    fn default() -> LcgRandom {
+        LcgRandom::new()
     }
-    
-    random_list
 }
 
 pub fn shuffle_list<T>(a: &mut Vec<T>){
@@ -106,4 +156,18 @@ mod tests {
         println!("{:?}", list);
     }
 
+    #[test]
+    fn test_seeded() {
+        use super::{lcg_generate_seeded, LcgRandom};
+
+        assert_eq!(lcg_generate_seeded(50, 7), lcg_generate_seeded(50, 7));
+        assert_ne!(lcg_generate_seeded(50, 7), lcg_generate_seeded(50, 8));
+
+        let mut random = LcgRandom::with_seed(7);
+        for _ in 0..1000 {
+            assert!(random.uniform(3) < 3);
+            let f = random.next_f64();
+            assert!((0.0..1.0).contains(&f));
+        }
+    }
 }
@@ -1,6 +1,16 @@
-use std::{cmp::Ordering::{*, self}, mem};
+use std::{cmp::Ordering::{*, self}, mem, ops::{Bound, RangeBounds}};
+
+use crate::fundamentals::{queue::Queue, stack::Stack};
+
+use super::search_tree::{self, height, size, TreeNode};
 /// The BST class represents an ordered symbol table og generic key pair values
 /// It supports the operations `put`, `get`, `delete_max` and `delete_min`.
+/// It also supports the ordered operations `min`, `max`, `floor`, `ceiling`, `rank`, `select`, `keys_between` and `size_between`,
+/// where every node keeps the size of its subtree so `rank` and `select` run in time proportional to the height.
+///
+/// The entries can be traversed in order with `iter`, `iter_mut` and `into_iter`, which also run backwards,
+/// in order between two bounds with `range`, and level by level from the root with `level_order`.
+/// A BST can be built from an iterator of key value pairs with `collect` and `extend`.
 /// 
 /// Every method should take constant worst-case running time: *O(N)*. With an average of *O(log N)*
 /// Author: AlbertRossJoh
@@ -24,6 +34,16 @@ use std::{cmp::Ordering::{*, self}, mem};
 /// another_bst.put(11, "val11");
 /// 
 /// assert_eq!(another_bst.get(&2).unwrap(), &"val2");
+/// assert_eq!(another_bst.floor(&9), Some(&4));
+/// assert_eq!(another_bst.rank(&10), 3);
+/// assert_eq!(another_bst.select(1), Some(&3));
+/// assert_eq!(another_bst.size_between(&3, &10), 3);
+///
+/// let keys: Vec<&u8> = another_bst.range(3..11).map(|(k, _)| k).collect();
+/// assert_eq!(keys, vec![&3, &4, &10]);
+///
+/// let squares: BST<u32,u32> = (1..5).map(|i| (i, i * i)).collect();
+/// assert_eq!(squares.iter().next_back(), Some((&4, &16)));
 /// 
 /// ```
 
@@ -38,6 +58,8 @@ struct Node<T,K> {
     value: Value<T,K>,
     left: Edge<T,K>,
     right: Edge<T,K>,
+    // the amount of nodes in the subtree rooted here
+    size: usize,
 }
 
 
@@ -45,39 +67,88 @@ impl<T, K> Node<T,K>
     where T:Ord
     {
     fn new(key: T, val: K) -> Self {
-        Node { value: Value{ key: key, val: val }, left: None , right: None }
-    }
-    fn cmp(&self, other: &Node<T,K>) -> Ordering{
-        other.value.key.cmp(&self.value.key)
+        Node { value: Value{ key, val }, left: None , right: None, size: 1 }
     }
-
     fn cmp_to_key(&self, key: &T) -> Ordering {
         key.cmp(&self.value.key)
     }
 }
+impl<T,K> TreeNode for Node<T,K> {
+    type Key = T;
+    type Val = K;
+
+    fn key(&self) -> &T {
+        &self.value.key
+    }
+
+    fn val(&self) -> &K {
+        &self.value.val
+    }
+
+    fn left(&self) -> &Edge<T,K> {
+        &self.left
+    }
+
+    fn right(&self) -> &Edge<T,K> {
+        &self.right
+    }
+
+    fn left_mut(&mut self) -> &mut Edge<T,K> {
+        &mut self.left
+    }
+
+    fn right_mut(&mut self) -> &mut Edge<T,K> {
+        &mut self.right
+    }
+
+    fn size(&self) -> usize {
+        self.size
+    }
+
+    fn update(&mut self) {
+        self.size = 1 + size(&self.left) + size(&self.right);
+    }
+}
+
+/// Finds the link holding the smallest node, by looking at a node before borrowing its left link mutably
 fn get_min_node_mut<T,K>(node:&mut Edge<T,K>) -> &mut Edge<T,K>{
-    let mut curr: *mut Edge<T,K> = node;
-    unsafe{
-        while let Some(ref mut e) = *curr {
-            if e.left.is_none() {
-                break;
-            }
-            curr = &mut e.left;
-        }
-        return &mut *curr;
+    let mut curr = node;
+    while curr.as_ref().is_some_and(|e| e.left.is_some()) {
+        curr = &mut curr.as_mut().unwrap().left;
     }
+    curr
 }
 
+/// Finds the link holding the largest node, by looking at a node before borrowing its right link mutably
 fn get_max_node_mut<T,K>(node:&mut Edge<T,K>) -> &mut Edge<T,K>{
-    let mut curr: *mut Edge<T,K> = node;
-    unsafe{
-        while let Some(ref mut e) = *curr {
-            if e.right.is_none() {
-                break;
-            }
-            curr = &mut e.right;
+    let mut curr = node;
+    while curr.as_ref().is_some_and(|e| e.right.is_some()) {
+        curr = &mut curr.as_mut().unwrap().right;
+    }
+    curr
+}
+
+/// Decrements the size of every node on the way to the smallest node, which is about to be removed
+fn shrink_min_path<T,K>(node:&mut Edge<T,K>){
+    let mut curr = node;
+    while let Some(e) = curr {
+        if e.left.is_none() {
+            break;
+        }
+        e.size -= 1;
+        curr = &mut e.left;
+    }
+}
+
+/// Decrements the size of every node on the way to the largest node, which is about to be removed
+fn shrink_max_path<T,K>(node:&mut Edge<T,K>){
+    let mut curr = node;
+    while let Some(e) = curr {
+        if e.right.is_none() {
+            break;
         }
-        return &mut *curr;
+        e.size -= 1;
+        curr = &mut e.right;
     }
 }
 
@@ -101,14 +172,34 @@ impl<T,K> BST<T,K>
     }
 
     pub fn delete_max(&mut self){
+        shrink_max_path(&mut self.root);
         Self::del(get_max_node_mut(&mut self.root))
     }
 
     pub fn delete_min(&mut self){
+        shrink_min_path(&mut self.root);
         Self::del(get_min_node_mut(&mut self.root))
     }
 
     pub fn delete(&mut self, key: &T){
+        if !self.contains(key) {
+            return;
+        }
+        // every node above the deleted one loses a node in its subtree
+        let mut curr = &mut self.root;
+        while let Some(node) = curr {
+            match node.cmp_to_key(key) {
+                Less => {
+                    node.size -= 1;
+                    curr = &mut node.left;
+                },
+                Greater => {
+                    node.size -= 1;
+                    curr = &mut node.right;
+                },
+                Equal => break,
+            }
+        }
         let curr = self.get_mut(key);
         Self::del(curr);
     }
@@ -124,8 +215,10 @@ impl<T,K> BST<T,K>
                 (left, right) => {
                     e.left = left;
                     e.right = right;
+                    e.size -= 1;
                     {
                         let tmp = &mut *e;
+                        shrink_min_path(&mut tmp.right);
                         let succ = get_min_node_mut(&mut tmp.right);
                         mem::swap(&mut tmp.value, &mut succ.as_mut().unwrap().value);
                         Self::del(succ);
@@ -136,22 +229,17 @@ impl<T,K> BST<T,K>
         }
     }
     
+    /// Finds the link holding the key, or the empty link where it would be inserted
     fn get_mut(&mut self, key: &T) -> &mut Edge<T,K>
         where T:Ord
     {
-        let mut curr: *mut Edge<T,K> = &mut self.root;
-        unsafe {
-            while let Some(ref mut node) = *curr {
-                match node.cmp_to_key(&key) {
-                    Less => curr = &mut node.left,
-                    Greater => curr = &mut node.right,
-                    Equal => {
-                        return &mut *curr;
-                    },
-                }
+        let mut curr = &mut self.root;
+        loop {
+            match curr.as_ref().map(|node| node.cmp_to_key(key)) {
+                Some(Less) => curr = &mut curr.as_mut().unwrap().left,
+                Some(Greater) => curr = &mut curr.as_mut().unwrap().right,
+                _ => return curr,
             }
-            return &mut *curr;
-
         }
     }
 
@@ -175,37 +263,650 @@ impl<T,K> BST<T,K>
     pub fn put(&mut self, key: T, val: K) 
         where T:Ord
     {
-        let to_insert = Node::new(key, val);
-        if self.root.is_none() {
-            self.root = Some(Box::new(to_insert));
-            return;
+        let is_new = !self.contains(&key);
+        let mut curr = &mut self.root;
+        while let Some(node) = curr {
+            if is_new {
+                node.size += 1;
+            }
+            match node.cmp_to_key(&key) {
+                Less => curr = &mut node.left,
+                Greater => curr = &mut node.right,
+                Equal => {
+                    node.value.val = val;
+                    return;
+                },
+            }
         }
-        let mut curr: *mut Edge<T,K> = &mut self.root;
-        unsafe{
-            while let Some(ref mut node) = *curr {
-                match node.cmp(&to_insert) {
-                    Less => curr = &mut node.left,
-                    Greater => curr = &mut node.right,
-                    Equal => {
-                        node.value.val = to_insert.value.val;
-                        return;
-                    },
-                }
+        *curr = Some(Box::new(Node::new(key, val)));
+    }
+
+    /// The entries in order of their keys
+    pub fn iter(&self) -> Iter<'_,T,K> {
+        self.range(..)
+    }
+
+    /// The entries with a key inside the range, in order of their keys. It is lazy, and a step takes amortized constant time
+    pub fn range<R: RangeBounds<T>>(&self, range: R) -> Iter<'_,T,K> {
+        let below_lo = match range.start_bound() {
+            Bound::Included(lo) => self.rank(lo),
+            Bound::Excluded(lo) => self.rank(lo) + if self.contains(lo) { 1 } else { 0 },
+            Bound::Unbounded => 0,
+        };
+        let up_to_hi = match range.end_bound() {
+            Bound::Included(hi) => self.rank(hi) + if self.contains(hi) { 1 } else { 0 },
+            Bound::Excluded(hi) => self.rank(hi),
+            Bound::Unbounded => self.size(),
+        };
+
+        // the front stack holds the nodes whose left subtree is being visited, starting with the path to the first key in the range
+        let mut front = Stack::new();
+        let mut curr = &self.root;
+        while let Some(node) = curr {
+            let after_lo = match range.start_bound() {
+                Bound::Included(lo) => *lo <= node.value.key,
+                Bound::Excluded(lo) => *lo < node.value.key,
+                Bound::Unbounded => true,
+            };
+            if after_lo {
+                front.push(&**node);
+                curr = &node.left;
+            } else {
+                curr = &node.right;
             }
-            curr.replace(Some(Box::new(to_insert)));
-            return;
         }
+        let mut back = Stack::new();
+        let mut curr = &self.root;
+        while let Some(node) = curr {
+            let before_hi = match range.end_bound() {
+                Bound::Included(hi) => node.value.key <= *hi,
+                Bound::Excluded(hi) => node.value.key < *hi,
+                Bound::Unbounded => true,
+            };
+            if before_hi {
+                back.push(&**node);
+                curr = &node.right;
+            } else {
+                curr = &node.left;
+            }
+        }
+        Iter { front, back, remaining: up_to_hi.saturating_sub(below_lo) }
+    }
+
+    /// The entries in order of their keys, where the values can be changed. It is lazy like `iter`
+    pub fn iter_mut(&mut self) -> IterMut<'_,T,K> {
+        let remaining = self.size();
+        IterMut { walk: Walk::new(&mut self.root, remaining) }
+    }
+
+    /// The entries level by level from the root, and from left to right inside a level
+    pub fn level_order(&self) -> LevelOrder<'_,T,K> {
+        let mut queue = Queue::new();
+        if let Some(node) = &self.root {
+            queue.enqueue(&**node);
+        }
+        LevelOrder { queue }
+    }
+
+    /// The amount of key value pairs
+    pub fn size(&self) -> usize {
+        size(&self.root)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    /// The height of the tree, where a tree with a single node has height 0 and the empty tree has height -1
+    pub fn height(&self) -> isize {
+        height(&self.root)
+    }
+
+    pub fn contains(&self, key: &T) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// The smallest key
+    pub fn min(&self) -> Option<&T> {
+        search_tree::min(&self.root)
+    }
+
+    /// The largest key
+    pub fn max(&self) -> Option<&T> {
+        search_tree::max(&self.root)
+    }
+
+    /// The largest key smaller than or equal to key
+    pub fn floor(&self, key: &T) -> Option<&T> {
+        search_tree::floor(&self.root, key)
+    }
+
+    /// The smallest key larger than or equal to key
+    pub fn ceiling(&self, key: &T) -> Option<&T> {
+        search_tree::ceiling(&self.root, key)
+    }
+
+    /// The amount of keys strictly smaller than key
+    pub fn rank(&self, key: &T) -> usize {
+        search_tree::rank(&self.root, key)
+    }
+
+    /// The key of rank r, which is the r+1'th smallest key
+    pub fn select(&self, r: usize) -> Option<&T> {
+        search_tree::select(&self.root, r)
+    }
+
+    /// All the keys, in order
+    pub fn keys(&self) -> Queue<&T> {
+        search_tree::keys(&self.root)
+    }
+
+    /// The keys between lo and hi, both inclusive, in order
+    pub fn keys_between(&self, lo: &T, hi: &T) -> Queue<&T> {
+        search_tree::keys_between(&self.root, lo, hi)
+    }
+
+    /// The amount of keys between lo and hi, both inclusive
+    pub fn size_between(&self, lo: &T, hi: &T) -> usize {
+        search_tree::size_between(&self.root, lo, hi)
     }
 
     
 }
 
+/// Pushes the node and its chain of left children
+fn push_left<'a,T,K>(stack: &mut Stack<&'a Node<T,K>>, mut node: &'a Edge<T,K>){
+    while let Some(e) = node {
+        stack.push(&**e);
+        node = &e.left;
+    }
+}
+
+/// Pushes the node and its chain of right children
+fn push_right<'a,T,K>(stack: &mut Stack<&'a Node<T,K>>, mut node: &'a Edge<T,K>){
+    while let Some(e) = node {
+        stack.push(&**e);
+        node = &e.right;
+    }
+}
+
+/// A subtree which can be taken apart into its left subtree, its entry and its right subtree,
+/// either by borrowing it mutably or by taking ownership of it
+trait Subtree: Sized {
+    type Entry;
+
+    /// The left subtree, the entry and the right subtree, or None if the subtree is empty
+    fn split(self) -> Option<(Self, Self::Entry, Self)>;
+}
+
+impl<'a,T,K> Subtree for &'a mut Edge<T,K> {
+    type Entry = (&'a T, &'a mut K);
+
+    fn split(self) -> Option<(Self, Self::Entry, Self)> {
+        let Node { value, left, right, .. } = &mut **self.as_mut()?;
+        Some((left, (&value.key, &mut value.val), right))
+    }
+}
+
+impl<T,K> Subtree for Edge<T,K> {
+    type Entry = (T, K);
+
+    fn split(self) -> Option<(Self, Self::Entry, Self)> {
+        let Node { value, left, right, .. } = *self?;
+        Some((left, (value.key, value.val), right))
+    }
+}
+
+/// The lazy in order walk behind `IterMut` and `IntoIter`, which take the subtrees apart as they go.
+/// A subtree can only be handed to one of the ends, so the untouched tree waits in the middle
+/// until one of the ends needs it. The front holds entries followed by their right subtree, smallest on top,
+/// and the back holds entries preceded by their left subtree, largest on top.
+/// When an end runs dry while the middle is gone it takes the innermost part of the other end,
+/// so the walk holds *O(h)* parts, where h is the height of the tree.
+struct Walk<S: Subtree> {
+    front: Vec<(S::Entry, Option<S>)>,
+    middle: Option<S>,
+    back: Vec<(Option<S>, S::Entry)>,
+    remaining: usize,
+}
+
+impl<S: Subtree> Walk<S> {
+    fn new(root: S, remaining: usize) -> Self {
+        Walk { front: Vec::new(), middle: Some(root), back: Vec::new(), remaining }
+    }
+
+    /// Pushes the entries along the chain of left children of the subtree
+    fn push_front(&mut self, mut tree: S) {
+        while let Some((left, entry, right)) = tree.split() {
+            self.front.push((entry, Some(right)));
+            tree = left;
+        }
+    }
+
+    /// Pushes the entries along the chain of right children of the subtree
+    fn push_back(&mut self, mut tree: S) {
+        while let Some((left, entry, right)) = tree.split() {
+            self.back.push((Some(left), entry));
+            tree = right;
+        }
+    }
+
+    fn next(&mut self) -> Option<S::Entry> {
+        if self.front.is_empty() {
+            if let Some(middle) = self.middle.take() {
+                self.push_front(middle);
+            } else if !self.back.is_empty() {
+                let (left, entry) = self.back.remove(0);
+                self.front.push((entry, None));
+                if let Some(left) = left {
+                    self.push_front(left);
+                }
+            }
+        }
+        let (entry, right) = self.front.pop()?;
+        if let Some(right) = right {
+            self.push_front(right);
+        }
+        self.remaining -= 1;
+        Some(entry)
+    }
+
+    fn next_back(&mut self) -> Option<S::Entry> {
+        if self.back.is_empty() {
+            if let Some(middle) = self.middle.take() {
+                self.push_back(middle);
+            } else if !self.front.is_empty() {
+                let (entry, right) = self.front.remove(0);
+                self.back.push((None, entry));
+                if let Some(right) = right {
+                    self.push_back(right);
+                }
+            }
+        }
+        let (left, entry) = self.back.pop()?;
+        if let Some(left) = left {
+            self.push_back(left);
+        }
+        self.remaining -= 1;
+        Some(entry)
+    }
+}
+
+/// An in order iterator over the entries of a BST, created by `iter` and `range`.
+/// One stack walks forwards from the smallest key and another walks backwards from the largest,
+/// and the amount of keys left in the range tells when they meet.
+pub struct Iter<'a,T,K> {
+    front: Stack<&'a Node<T,K>>,
+    back: Stack<&'a Node<T,K>>,
+    remaining: usize,
+}
+
+impl<'a,T,K> Iterator for Iter<'a,T,K> {
+    type Item = (&'a T, &'a K);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let node = self.front.pop()?;
+        push_left(&mut self.front, &node.right);
+        self.remaining -= 1;
+        Some((&node.value.key, &node.value.val))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a,T,K> DoubleEndedIterator for Iter<'a,T,K> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let node = self.back.pop()?;
+        push_right(&mut self.back, &node.left);
+        self.remaining -= 1;
+        Some((&node.value.key, &node.value.val))
+    }
+}
+
+/// An in order iterator over the entries of a BST where the values can be changed, created by `iter_mut`
+pub struct IterMut<'a,T,K> {
+    walk: Walk<&'a mut Edge<T,K>>,
+}
+
+impl<'a,T,K> Iterator for IterMut<'a,T,K> {
+    type Item = (&'a T, &'a mut K);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.walk.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.walk.remaining, Some(self.walk.remaining))
+    }
+}
+
+impl<'a,T,K> DoubleEndedIterator for IterMut<'a,T,K> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.walk.next_back()
+    }
+}
+
+/// An in order iterator which takes the entries out of a BST, created by `into_iter`.
+/// The nodes are freed as the walk passes them
+pub struct IntoIter<T,K> {
+    walk: Walk<Edge<T,K>>,
+}
+
+impl<T,K> Iterator for IntoIter<T,K> {
+    type Item = (T, K);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.walk.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.walk.remaining, Some(self.walk.remaining))
+    }
+}
+
+impl<T,K> DoubleEndedIterator for IntoIter<T,K> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.walk.next_back()
+    }
+}
+
+/// A level order iterator over the entries of a BST, created by `level_order`
+pub struct LevelOrder<'a,T,K> {
+    queue: Queue<&'a Node<T,K>>,
+}
+
+impl<'a,T,K> Iterator for LevelOrder<'a,T,K> {
+    type Item = (&'a T, &'a K);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.queue.dequeue()?;
+        for child in [&node.left, &node.right].into_iter().flatten() {
+            self.queue.enqueue(&**child);
+        }
+        Some((&node.value.key, &node.value.val))
+    }
+}
+
+impl<T:Ord,K> IntoIterator for BST<T,K> {
+    type Item = (T, K);
+    type IntoIter = IntoIter<T,K>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let remaining = self.size();
+        IntoIter { walk: Walk::new(self.root, remaining) }
+    }
+}
+
+impl<'a,T:Ord,K> IntoIterator for &'a BST<T,K> {
+    type Item = (&'a T, &'a K);
+    type IntoIter = Iter<'a,T,K>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a,T:Ord,K> IntoIterator for &'a mut BST<T,K> {
+    type Item = (&'a T, &'a mut K);
+    type IntoIter = IterMut<'a,T,K>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T:Ord,K> Extend<(T, K)> for BST<T,K> {
+    fn extend<I: IntoIterator<Item = (T, K)>>(&mut self, iter: I) {
+        for (key, val) in iter {
+            self.put(key, val);
+        }
+    }
+}
+
+impl<T:Ord,K> FromIterator<(T, K)> for BST<T,K> {
+    fn from_iter<I: IntoIterator<Item = (T, K)>>(iter: I) -> Self {
+        let mut bst = BST::new();
+        bst.extend(iter);
+        bst
+    }
+}
+
 
 #[cfg(test)]
 mod tests {
     use super::BST;
     // use crate itualgs_rs::searching::BST;
 
+    use std::collections::BTreeMap;
+    use crate::randomization::lcg_random::lcg_generate_seeded;
+
+    /// Checks that the size of every node is the size of its subtree
+    fn sizes_are_consistent(node: &super::Edge<u32,usize>) -> bool {
+        match node {
+            None => true,
+            Some(e) => e.size == 1 + super::size(&e.left) + super::size(&e.right)
+                && sizes_are_consistent(&e.left) && sizes_are_consistent(&e.right),
+        }
+    }
+
+    /// Checks the symmetric order and the sizes of every node, and returns the size of the subtree
+    fn check_invariants(node: &super::Edge<u32,usize>, lo: Option<u32>, hi: Option<u32>) -> usize {
+        match node {
+            None => 0,
+            Some(e) => {
+                let key = e.value.key;
+                assert!(lo.is_none_or(|lo| lo < key), "{} is not above {:?}", key, lo);
+                assert!(hi.is_none_or(|hi| key < hi), "{} is not below {:?}", key, hi);
+                let size = 1 + check_invariants(&e.left, lo, Some(key)) + check_invariants(&e.right, Some(key), hi);
+                assert_eq!(e.size, size);
+                size
+            }
+        }
+    }
+
+    #[test]
+    fn fuzz_against_btreemap() {
+        use crate::randomization::lcg_random::LcgRandom;
+
+        for seed in 0..50 {
+            let mut random = LcgRandom::with_seed(seed);
+            let mut bst: BST<u32,usize> = BST::new();
+            let mut expected: BTreeMap<u32,usize> = BTreeMap::new();
+            // a small key space makes hits on existing keys likely
+            let keys = 1 + random.uniform(100) as u32;
+            for i in 0..400 {
+                let key = random.uniform(keys as usize) as u32;
+                match random.uniform(10) {
+                    0..=3 => {
+                        bst.put(key, i);
+                        expected.insert(key, i);
+                    },
+                    4 | 5 => {
+                        bst.delete(&key);
+                        expected.remove(&key);
+                    },
+                    6 => {
+                        bst.delete_min();
+                        expected.pop_first();
+                    },
+                    7 => {
+                        bst.delete_max();
+                        expected.pop_last();
+                    },
+                    _ => assert_eq!(bst.get(&key), expected.get(&key), "seed {}", seed),
+                }
+                assert_eq!(check_invariants(&bst.root, None, None), expected.len(), "seed {}", seed);
+            }
+            assert!(bst.iter().eq(expected.iter()), "seed {}", seed);
+        }
+    }
+
+    #[test]
+    fn test_ordered_operations() {
+        let rand = lcg_generate_seeded(4000, 41);
+        let mut bst: BST<u32,usize> = BST::new();
+        let mut expected: BTreeMap<u32,usize> = BTreeMap::new();
+        for (i, c) in rand.chunks(2).enumerate() {
+            let key = c[1] % 300;
+            match c[0] % 6 {
+                0 => {
+                    bst.delete(&key);
+                    expected.remove(&key);
+                },
+                1 => {
+                    bst.delete_min();
+                    expected.pop_first();
+                },
+                2 => {
+                    bst.delete_max();
+                    expected.pop_last();
+                },
+                _ => {
+                    bst.put(key, i);
+                    expected.insert(key, i);
+                },
+            }
+            assert!(sizes_are_consistent(&bst.root));
+            assert_eq!(bst.size(), expected.len());
+        }
+
+        assert_eq!(bst.min(), expected.keys().next());
+        assert_eq!(bst.max(), expected.keys().next_back());
+        for key in 0..310 {
+            assert_eq!(bst.contains(&key), expected.contains_key(&key));
+            assert_eq!(bst.floor(&key), expected.range(..=key).next_back().map(|(k, _)| k));
+            assert_eq!(bst.ceiling(&key), expected.range(key..).next().map(|(k, _)| k));
+            assert_eq!(bst.rank(&key), expected.range(..key).count());
+        }
+        for (r, key) in expected.keys().enumerate() {
+            assert_eq!(bst.select(r), Some(key));
+        }
+        assert_eq!(bst.select(expected.len()), None);
+
+        let (lo, hi) = (40, 180);
+        let mut keys = bst.keys_between(&lo, &hi);
+        for key in expected.range(lo..=hi).map(|(k, _)| k) {
+            assert_eq!(keys.dequeue(), Some(key));
+        }
+        assert!(keys.is_empty());
+        assert_eq!(bst.size_between(&lo, &hi), expected.range(lo..=hi).count());
+        assert_eq!(bst.size_between(&hi, &lo), 0);
+        assert!(bst.height() >= (bst.size() as f64).log2() as isize);
+    }
+
+    #[test]
+    fn test_iterators() {
+        let rand = lcg_generate_seeded(600, 42);
+        let mut bst: BST<u32,usize> = rand.iter().enumerate().map(|(i, r)| (r % 500, i)).collect();
+        let expected: BTreeMap<u32,usize> = rand.iter().enumerate().map(|(i, r)| (r % 500, i)).collect();
+
+        assert!(bst.iter().eq(expected.iter()));
+        assert!(bst.iter().rev().eq(expected.iter().rev()));
+        assert_eq!(bst.iter().size_hint(), (expected.len(), Some(expected.len())));
+
+        // walking from both ends at once meets in the middle
+        let mut iter = bst.iter();
+        let mut both = Vec::new();
+        while let Some(front) = iter.next() {
+            both.push(front);
+            if let Some(back) = iter.next_back() {
+                both.push(back);
+            }
+        }
+        both.sort();
+        assert!(both.into_iter().eq(expected.iter()));
+
+        // an empty range is empty, where a BTreeMap would panic
+        let (lo, hi) = (300, 100);
+        assert_eq!(bst.range(lo..hi).next(), None);
+        assert_eq!(bst.range(lo..=hi).next_back(), None);
+        for (lo, hi) in [(0, 500), (100, 300), (250, 250), (499, 600)] {
+            assert!(bst.range(lo..hi).eq(expected.range(lo..hi)));
+            assert!(bst.range(lo..=hi).rev().eq(expected.range(lo..=hi).rev()));
+            assert!(bst.range(lo..).eq(expected.range(lo..)));
+            assert!(bst.range(..hi).rev().eq(expected.range(..hi).rev()));
+        }
+        assert!(bst.range((std::ops::Bound::Excluded(100), std::ops::Bound::Unbounded)).eq(expected.range(101..)));
+
+        for (_, val) in bst.iter_mut() {
+            *val += 1;
+        }
+        for (_, val) in &mut bst {
+            *val *= 2;
+        }
+        assert!(bst.iter_mut().rev().map(|(k, v)| (*k, *v)).eq(expected.iter().rev().map(|(k, v)| (*k, (v + 1) * 2))));
+
+        let mut level = bst.level_order();
+        assert_eq!(level.next().map(|(k, _)| k), bst.root.as_ref().map(|e| &e.value.key));
+        assert_eq!(bst.level_order().count(), bst.size());
+
+        bst.extend(vec![(1000, 0), (1001, 1)]);
+        let owned: Vec<(u32, usize)> = bst.into_iter().rev().take(2).collect();
+        assert_eq!(owned, vec![(1001, 1), (1000, 0)]);
+    }
+
+    #[test]
+    fn test_mutable_and_owned_iterators_from_both_ends() {
+        // a random tree and a path, which only has right children
+        let rand = lcg_generate_seeded(300, 42);
+        let trees: [Vec<u32>; 2] = [rand.iter().map(|r| r % 200).collect(), (0..100).collect()];
+        for (t, keys) in trees.iter().enumerate() {
+            let expected: BTreeMap<u32,u32> = keys.iter().map(|k| (*k, 0)).collect();
+            // the pattern of which end to take from, from the same seeded sequence for every iterator
+            let ends = lcg_generate_seeded(expected.len(), t as u64);
+
+            let mut bst: BST<u32,u32> = keys.iter().map(|k| (*k, 0)).collect();
+            let mut deque: std::collections::VecDeque<u32> = expected.keys().copied().collect();
+            let mut iter = bst.iter_mut();
+            for (i, end) in ends.iter().enumerate() {
+                assert_eq!(iter.size_hint().0, expected.len() - i);
+                let (key, val) = if end % 2 == 0 { iter.next() } else { iter.next_back() }.unwrap();
+                let want = if end % 2 == 0 { deque.pop_front() } else { deque.pop_back() };
+                assert_eq!(Some(*key), want);
+                *val = *key + 1;
+            }
+            assert!(iter.next().is_none() && iter.next_back().is_none());
+            assert!(bst.iter().all(|(k, v)| *v == k + 1));
+
+            let mut deque: std::collections::VecDeque<u32> = expected.keys().copied().collect();
+            let mut owned = bst.into_iter();
+            for end in ends.iter() {
+                let entry = if end % 2 == 0 { owned.next() } else { owned.next_back() };
+                let want = if end % 2 == 0 { deque.pop_front() } else { deque.pop_back() };
+                assert_eq!(entry, want.map(|k| (k, k + 1)));
+            }
+            assert_eq!(owned.next(), None);
+        }
+    }
+
+    #[test]
+    fn test_level_order() {
+        let bst: BST<u8,()> = [4, 2, 6, 1, 3, 5, 7].iter().map(|k| (*k, ())).collect();
+        let keys: Vec<u8> = bst.level_order().map(|(k, _)| *k).collect();
+        assert_eq!(keys, vec![4, 2, 6, 1, 3, 5, 7]);
+        assert_eq!(BST::<u8,()>::new().iter().next(), None);
+    }
+
+    #[test]
+    fn test_empty_ordered_operations() {
+        let bst: BST<i32,&str> = BST::new();
+        assert!(bst.is_empty());
+        assert_eq!(bst.height(), -1);
+        assert_eq!(bst.min(), None);
+        assert_eq!(bst.floor(&3), None);
+        assert_eq!(bst.select(0), None);
+        assert_eq!(bst.rank(&3), 0);
+        assert_eq!(bst.size_between(&0, &10), 0);
+    }
+
     #[test]
     fn test_deletion() {
        let mut bst: BST<i32,&str> = BST::new();
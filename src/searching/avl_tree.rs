@@ -0,0 +1,343 @@
+use std::cmp::Ordering::*;
+
+use crate::fundamentals::queue::Queue;
+
+use super::search_tree::{self, rotate_left, rotate_right, size, TreeNode};
+
+/// The AVLTreeST class represents an ordered symbol table of generic key value pairs,
+/// stored in an AVL tree, which is a binary search tree where the heights of the two subtrees of every node differ by at most one.
+/// It supports the operations `put`, `get`, `contains`, `delete`, `delete_max` and `delete_min`, together with `size` and `height`,
+/// and the ordered operations `min`, `max`, `floor`, `ceiling`, `rank`, `select`, `keys_between` and `size_between`.
+///
+/// Every node stores its height, and a node whose subtrees differ by two after an insertion or a deletion is fixed with one or two rotations.
+/// This keeps the height below *1.44 log N*, so every operation takes *O(log N)* time in the worst case, also when the keys are inserted in order.
+/// The tree is more strictly balanced than a red-black tree, so searches are a little faster and updates do a little more work.
+///
+/// For additional documentation, see Section 3.3 of Algorithms, 4th Edition by Robert Sedgewick and Kevin Wayne.
+///
+/// # Examples
+///
+/// ```
+/// use itualgs_rs::searching::avl_tree::AVLTreeST;
+///
+/// let mut avl: AVLTreeST<u32,&str> = AVLTreeST::new();
+/// for i in 0..1000 {
+///     avl.put(i, "val");
+/// }
+/// avl.put(4, "val4");
+///
+/// assert_eq!(avl.get(&4).unwrap(), &"val4");
+/// assert_eq!(avl.size(), 1000);
+/// assert!(avl.height() <= 14);
+///
+/// avl.delete(&4);
+/// avl.delete_min();
+/// assert!(!avl.contains(&4));
+/// assert_eq!(avl.min(), Some(&1));
+/// assert_eq!(avl.floor(&4), Some(&3));
+/// assert_eq!(avl.rank(&10), 8);
+/// ```
+pub struct AVLTreeST<T,K> {
+    root: Link<T,K>,
+}
+
+type Link<T,K> = Option<Box<Node<T,K>>>;
+
+struct Node<T,K> {
+    key: T,
+    val: K,
+    left: Link<T,K>,
+    right: Link<T,K>,
+    // the height of the subtree rooted in the node, which is 0 for a leaf
+    height: isize,
+    size: usize,
+}
+
+impl<T,K> TreeNode for Node<T,K> {
+    type Key = T;
+    type Val = K;
+
+    fn key(&self) -> &T {
+        &self.key
+    }
+
+    fn val(&self) -> &K {
+        &self.val
+    }
+
+    fn left(&self) -> &Link<T,K> {
+        &self.left
+    }
+
+    fn right(&self) -> &Link<T,K> {
+        &self.right
+    }
+
+    fn left_mut(&mut self) -> &mut Link<T,K> {
+        &mut self.left
+    }
+
+    fn right_mut(&mut self) -> &mut Link<T,K> {
+        &mut self.right
+    }
+
+    fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Recomputes the height and the size from the children
+    fn update(&mut self) {
+        self.height = 1 + height(&self.left).max(height(&self.right));
+        self.size = 1 + size(&self.left) + size(&self.right);
+    }
+}
+
+impl<T,K> Default for AVLTreeST<T,K>
+    where T:Ord
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T,K> AVLTreeST<T,K>
+    where T:Ord
+{
+    pub fn new() -> Self {
+        AVLTreeST { root: None }
+    }
+
+    /// The amount of key value pairs
+    pub fn size(&self) -> usize {
+        size(&self.root)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    /// The height of the tree, where a tree with a single node has height 0 and the empty tree has height -1.
+    /// The height is stored in the root, so this takes constant time
+    pub fn height(&self) -> isize {
+        height(&self.root)
+    }
+
+    pub fn get(&self, key: &T) -> Option<&K> {
+        search_tree::get(&self.root, key)
+    }
+
+    pub fn contains(&self, key: &T) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// The smallest key
+    pub fn min(&self) -> Option<&T> {
+        search_tree::min(&self.root)
+    }
+
+    /// The largest key
+    pub fn max(&self) -> Option<&T> {
+        search_tree::max(&self.root)
+    }
+
+    /// The largest key smaller than or equal to key
+    pub fn floor(&self, key: &T) -> Option<&T> {
+        search_tree::floor(&self.root, key)
+    }
+
+    /// The smallest key larger than or equal to key
+    pub fn ceiling(&self, key: &T) -> Option<&T> {
+        search_tree::ceiling(&self.root, key)
+    }
+
+    /// The amount of keys strictly smaller than key
+    pub fn rank(&self, key: &T) -> usize {
+        search_tree::rank(&self.root, key)
+    }
+
+    /// The key of rank r, which is the r+1'th smallest key
+    pub fn select(&self, r: usize) -> Option<&T> {
+        search_tree::select(&self.root, r)
+    }
+
+    /// All the keys, in order
+    pub fn keys(&self) -> Queue<&T> {
+        search_tree::keys(&self.root)
+    }
+
+    /// The keys between lo and hi, both inclusive, in order
+    pub fn keys_between(&self, lo: &T, hi: &T) -> Queue<&T> {
+        search_tree::keys_between(&self.root, lo, hi)
+    }
+
+    /// The amount of keys between lo and hi, both inclusive
+    pub fn size_between(&self, lo: &T, hi: &T) -> usize {
+        search_tree::size_between(&self.root, lo, hi)
+    }
+
+    /// Inserts the key value pair, overwriting the value if the key is already in the table
+    pub fn put(&mut self, key: T, val: K) {
+        self.root = Some(put(self.root.take(), key, val));
+    }
+
+    pub fn delete_min(&mut self) {
+        if let Some(root) = self.root.take() {
+            self.root = delete_min(root).0;
+        }
+    }
+
+    pub fn delete_max(&mut self) {
+        if let Some(root) = self.root.take() {
+            self.root = delete_max(root).0;
+        }
+    }
+
+    /// Removes the key and its value, if the key is in the table
+    pub fn delete(&mut self, key: &T) {
+        self.root = delete(self.root.take(), key);
+    }
+}
+
+fn height<T,K>(link: &Link<T,K>) -> isize {
+    link.as_ref().map_or(-1, |node| node.height)
+}
+
+fn put<T:Ord,K>(link: Link<T,K>, key: T, val: K) -> Box<Node<T,K>> {
+    let mut h = match link {
+        None => return Box::new(Node { key, val, left: None, right: None, height: 0, size: 1 }),
+        Some(h) => h,
+    };
+    match key.cmp(&h.key) {
+        Less => h.left = Some(put(h.left.take(), key, val)),
+        Greater => h.right = Some(put(h.right.take(), key, val)),
+        Equal => {
+            h.val = val;
+            return h;
+        }
+    }
+    balance(h)
+}
+
+/// Removes the smallest node below h, and returns what is left together with the removed node
+fn delete_min<T,K>(mut h: Box<Node<T,K>>) -> (Link<T,K>, Box<Node<T,K>>) {
+    let left = match h.left.take() {
+        None => return (h.right.take(), h),
+        Some(left) => left,
+    };
+    let (left, min) = delete_min(left);
+    h.left = left;
+    (Some(balance(h)), min)
+}
+
+/// Removes the largest node below h, and returns what is left together with the removed node
+fn delete_max<T,K>(mut h: Box<Node<T,K>>) -> (Link<T,K>, Box<Node<T,K>>) {
+    let right = match h.right.take() {
+        None => return (h.left.take(), h),
+        Some(right) => right,
+    };
+    let (right, max) = delete_max(right);
+    h.right = right;
+    (Some(balance(h)), max)
+}
+
+fn delete<T:Ord,K>(link: Link<T,K>, key: &T) -> Link<T,K> {
+    let mut h = link?;
+    match key.cmp(&h.key) {
+        Less => h.left = delete(h.left.take(), key),
+        Greater => h.right = delete(h.right.take(), key),
+        Equal => {
+            let right = match h.right.take() {
+                None => return h.left.take(),
+                Some(right) => right,
+            };
+            if h.left.is_none() {
+                return Some(right);
+            }
+            // the node takes the place of its successor, which is removed from the right subtree
+            let (right, mut min) = delete_min(right);
+            min.left = h.left.take();
+            min.right = right;
+            h = min;
+        }
+    }
+    Some(balance(h))
+}
+
+/// The height of the left subtree minus the height of the right subtree
+fn balance_factor<T,K>(h: &Node<T,K>) -> isize {
+    height(&h.left) - height(&h.right)
+}
+
+/// Restores the AVL property of h, whose subtrees are AVL trees with heights that differ by at most two
+fn balance<T,K>(mut h: Box<Node<T,K>>) -> Box<Node<T,K>> {
+    h.update();
+    if balance_factor(&h) > 1 {
+        // a left child leaning right is first rotated, so a single rotation of h is enough
+        if balance_factor(h.left.as_ref().unwrap()) < 0 {
+            h.left = Some(rotate_left(h.left.take().unwrap()));
+        }
+        h = rotate_right(h);
+    } else if balance_factor(&h) < -1 {
+        if balance_factor(h.right.as_ref().unwrap()) > 0 {
+            h.right = Some(rotate_right(h.right.take().unwrap()));
+        }
+        h = rotate_left(h);
+    }
+    h
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::searching::search_tree::tests::{check_empty, check_random_operations};
+
+    use super::{height, size, AVLTreeST, Link};
+
+    /// Checks the symmetric order, the sizes, the stored heights and the AVL property
+    fn check<T:Ord,K>(link: &Link<T,K>, lo: Option<&T>, hi: Option<&T>) {
+        if let Some(node) = link {
+            assert!(lo.is_none_or(|lo| *lo < node.key));
+            assert!(hi.is_none_or(|hi| node.key < *hi));
+            assert_eq!(node.size, 1 + size(&node.left) + size(&node.right));
+            assert_eq!(node.height, 1 + height(&node.left).max(height(&node.right)));
+            assert!((height(&node.left) - height(&node.right)).abs() <= 1);
+            check(&node.left, lo, Some(&node.key));
+            check(&node.right, Some(&node.key), hi);
+        }
+    }
+
+    fn check_tree<T:Ord,K>(avl: &AVLTreeST<T,K>) {
+        check(&avl.root, None, None);
+        let n = avl.size() as f64;
+        assert!(avl.height() as f64 <= 1.44 * (n + 2.0).log2());
+    }
+
+    #[test]
+    fn test_sorted_inserts(){
+        let mut avl: AVLTreeST<u32,u32> = AVLTreeST::new();
+        for i in 0..1023 {
+            avl.put(i, i * 2);
+            check_tree(&avl);
+        }
+        // keys inserted in order give a perfectly balanced tree
+        assert_eq!(avl.height(), 9);
+        assert_eq!(avl.get(&500), Some(&1000));
+        for i in (0..1023).rev() {
+            avl.delete(&i);
+            check_tree(&avl);
+        }
+        assert!(avl.is_empty());
+    }
+
+    #[test]
+    fn test_random_operations(){
+        check_random_operations(AVLTreeST::new(), 48, check_tree);
+    }
+
+    #[test]
+    fn test_empty(){
+        let avl = AVLTreeST::new();
+        assert_eq!(avl.height(), -1);
+        check_empty(avl);
+    }
+}
@@ -0,0 +1,706 @@
+use std::cell::Cell;
+
+use crate::fundamentals::{binary_search, queue::Queue};
+
+const DEFAULT_ORDER: usize = 4;
+
+/// The BTree class represents an ordered symbol table of generic key value pairs,
+/// stored in a B-tree of order M, where every node (page) holds up to M-1 keys in sorted order and an internal node with k keys has k+1 children.
+/// It supports the operations `put`, `get`, `contains`, `delete`, `delete_min`, `delete_max`, `size` and `height`,
+/// and the ordered operations `min`, `max`, `floor`, `ceiling`, `rank`, `select`, `keys`, `keys_between` and `size_between`.
+///
+/// A node that gets M keys is split in two and its middle key moves up to the parent, and the tree only grows when the root is split.
+/// A node that gets fewer than ceil(M/2)-1 keys borrows a key from a sibling, or is merged with it, and the tree only shrinks when the root is emptied.
+/// So all the leaves are at the same depth, and the height is at most *log_{ceil(M/2)} N*.
+/// An operation touches one node per level, and a node is searched with a binary search, so every operation takes *O(log N)* time.
+/// The pages do not know the sizes of their subtrees, so `rank`, `select` and `size_between` count the keys with the same scan as `keys_between`,
+/// and take time proportional to the amount of keys they pass.
+///
+/// Every node read or written is counted as a page access, which is how many pages a B-tree on a disk would load.
+/// The counter is reset at the start of every operation, so after an operation `page_accesses` returns what that operation cost.
+///
+/// For additional documentation, see Section 6.2 of Algorithms, 4th Edition by Robert Sedgewick and Kevin Wayne.
+///
+/// # Examples
+///
+/// ```
+/// use itualgs_rs::searching::b_tree::BTree;
+///
+/// let mut bt: BTree<u32,&str> = BTree::with_order(16);
+/// for i in 0..1000 {
+///     bt.put(i, "val");
+/// }
+/// bt.put(4, "val4");
+///
+/// assert_eq!(bt.get(&4), Some(&"val4"));
+/// assert_eq!(bt.size(), 1000);
+/// // a lookup of a missing key reads one page per level
+/// assert_eq!(bt.get(&5000), None);
+/// assert_eq!(bt.page_accesses() as isize, bt.height() + 1);
+///
+/// bt.delete(&4);
+/// bt.delete_min();
+/// assert!(!bt.contains(&4));
+/// assert_eq!(bt.floor(&4), Some(&3));
+/// assert_eq!(bt.rank(&10), 8);
+/// assert_eq!(bt.keys_between(&2, &6).size(), 4);
+/// ```
+pub struct BTree<T,K> {
+    root: Node<T,K>,
+    m: usize,
+    n: usize,
+    height: usize,
+    accesses: Cell<usize>,
+}
+
+// the middle key and the right half of a node that was split
+type Split<T,K> = Option<(T, K, Node<T,K>)>;
+
+/// A page of the tree, which is a leaf when it has no children
+struct Node<T,K> {
+    keys: Vec<T>,
+    vals: Vec<K>,
+    children: Vec<Node<T,K>>,
+}
+
+impl<T,K> Node<T,K> {
+    fn new() -> Self {
+        Node { keys: Vec::new(), vals: Vec::new(), children: Vec::new() }
+    }
+
+    fn is_leaf(&self) -> bool {
+        self.children.is_empty()
+    }
+}
+
+impl<T,K> Default for BTree<T,K>
+    where T:Ord
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T,K> BTree<T,K>
+    where T:Ord
+{
+    /// Creates an empty B-tree of order 4
+    pub fn new() -> Self {
+        Self::with_order(DEFAULT_ORDER)
+    }
+
+    /// Creates an empty B-tree of order m, where a node has at most m children
+    pub fn with_order(m: usize) -> Self {
+        if m < 3 {
+            panic!("The order of a B-tree has to be at least 3!")
+        }
+        BTree { root: Node::new(), m, n: 0, height: 0, accesses: Cell::new(0) }
+    }
+
+    /// The order of the tree, which is the largest amount of children a node can have
+    pub fn order(&self) -> usize {
+        self.m
+    }
+
+    /// The amount of key value pairs
+    pub fn size(&self) -> usize {
+        self.n
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+
+    /// The height of the tree, which is the amount of links from the root to a leaf, so a tree with a single node has height 0.
+    /// The empty tree is a single empty page, so its height is 0 as well
+    pub fn height(&self) -> isize {
+        self.height as isize
+    }
+
+    /// The amount of pages the last operation read or wrote
+    pub fn page_accesses(&self) -> usize {
+        self.accesses.get()
+    }
+
+    fn access(&self) {
+        self.accesses.set(self.accesses.get() + 1);
+    }
+
+    pub fn get(&self, key: &T) -> Option<&K> {
+        self.accesses.set(0);
+        let mut node = &self.root;
+        loop {
+            self.access();
+            let i = binary_search::rank(&node.keys, key);
+            if i < node.keys.len() && node.keys[i] == *key {
+                return Some(&node.vals[i]);
+            }
+            if node.is_leaf() {
+                return None;
+            }
+            node = &node.children[i];
+        }
+    }
+
+    pub fn contains(&self, key: &T) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// The smallest key
+    pub fn min(&self) -> Option<&T> {
+        self.accesses.set(0);
+        let mut node = &self.root;
+        self.access();
+        while let Some(child) = node.children.first() {
+            self.access();
+            node = child;
+        }
+        node.keys.first()
+    }
+
+    /// The largest key
+    pub fn max(&self) -> Option<&T> {
+        self.accesses.set(0);
+        let mut node = &self.root;
+        self.access();
+        while let Some(child) = node.children.last() {
+            self.access();
+            node = child;
+        }
+        node.keys.last()
+    }
+
+    /// The largest key smaller than or equal to key
+    pub fn floor(&self, key: &T) -> Option<&T> {
+        self.accesses.set(0);
+        let mut node = &self.root;
+        let mut best = None;
+        loop {
+            self.access();
+            let i = binary_search::rank(&node.keys, key);
+            if i < node.keys.len() && node.keys[i] == *key {
+                return Some(&node.keys[i]);
+            }
+            // the keys in child i are between keys i-1 and i, so a floor found there is closer
+            if i > 0 {
+                best = Some(&node.keys[i - 1]);
+            }
+            if node.is_leaf() {
+                return best;
+            }
+            node = &node.children[i];
+        }
+    }
+
+    /// The smallest key larger than or equal to key
+    pub fn ceiling(&self, key: &T) -> Option<&T> {
+        self.accesses.set(0);
+        let mut node = &self.root;
+        let mut best = None;
+        loop {
+            self.access();
+            let i = binary_search::rank(&node.keys, key);
+            if i < node.keys.len() {
+                if node.keys[i] == *key {
+                    return Some(&node.keys[i]);
+                }
+                best = Some(&node.keys[i]);
+            }
+            if node.is_leaf() {
+                return best;
+            }
+            node = &node.children[i];
+        }
+    }
+
+    /// The amount of keys strictly smaller than key, which are counted with a scan from the smallest key
+    pub fn rank(&self, key: &T) -> usize {
+        self.accesses.set(0);
+        let mut rank = 0;
+        self.scan(&self.root, None, Some(key), &mut |k| {
+            if k < key {
+                rank += 1;
+            }
+            true
+        });
+        rank
+    }
+
+    /// The key of rank r, which is the r+1'th smallest key, found with a scan from the smallest key
+    pub fn select(&self, r: usize) -> Option<&T> {
+        self.accesses.set(0);
+        if r >= self.n {
+            return None;
+        }
+        let mut seen = 0;
+        let mut found = None;
+        self.scan(&self.root, None, None, &mut |k| {
+            if seen == r {
+                found = Some(k);
+                return false;
+            }
+            seen += 1;
+            true
+        });
+        found
+    }
+
+    /// All the keys, in order
+    pub fn keys(&self) -> Queue<&T> {
+        self.accesses.set(0);
+        let mut queue = Queue::new();
+        self.scan(&self.root, None, None, &mut |k| {
+            queue.enqueue(k);
+            true
+        });
+        queue
+    }
+
+    /// The keys between lo and hi, both inclusive, in order.
+    /// Only the pages that can hold a key in the range are read
+    pub fn keys_between(&self, lo: &T, hi: &T) -> Queue<&T> {
+        self.accesses.set(0);
+        let mut queue = Queue::new();
+        if lo <= hi {
+            self.scan(&self.root, Some(lo), Some(hi), &mut |k| {
+                queue.enqueue(k);
+                true
+            });
+        }
+        queue
+    }
+
+    /// The amount of keys between lo and hi, both inclusive, which are counted with the scan of `keys_between`
+    pub fn size_between(&self, lo: &T, hi: &T) -> usize {
+        self.accesses.set(0);
+        let mut count = 0;
+        if lo <= hi {
+            self.scan(&self.root, Some(lo), Some(hi), &mut |_| {
+                count += 1;
+                true
+            });
+        }
+        count
+    }
+
+    /// Visits the keys below node which are between lo and hi in order, where a missing bound is unbounded.
+    /// The scan stops when visit returns false, and it returns false when it was stopped or went past hi
+    fn scan<'a>(&self, node: &'a Node<T,K>, lo: Option<&T>, hi: Option<&T>, visit: &mut impl FnMut(&'a T) -> bool) -> bool {
+        self.access();
+        // the keys before start are smaller than lo, and so is everything in the children before start
+        let start = lo.map_or(0, |lo| binary_search::rank(&node.keys, lo));
+        for i in start..=node.keys.len() {
+            if let Some(child) = node.children.get(i) {
+                if !self.scan(child, lo, hi, visit) {
+                    return false;
+                }
+            }
+            let Some(key) = node.keys.get(i) else {
+                break;
+            };
+            if hi.is_some_and(|hi| key > hi) || !visit(key) {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Inserts the key value pair, overwriting the value if the key is already in the table
+    pub fn put(&mut self, key: T, val: K) {
+        self.accesses.set(0);
+        let (added, split) = put(&mut self.root, key, val, self.m, &self.accesses);
+        if added {
+            self.n += 1;
+        }
+        if let Some((key, val, right)) = split {
+            // the root was split, so a new root is placed above the two halves
+            self.access();
+            let left = std::mem::replace(&mut self.root, Node::new());
+            self.root = Node { keys: vec![key], vals: vec![val], children: vec![left, right] };
+            self.height += 1;
+        }
+    }
+
+    /// Removes the key and its value, if the key is in the table
+    pub fn delete(&mut self, key: &T) {
+        self.accesses.set(0);
+        if delete(&mut self.root, key, self.m, &self.accesses).is_some() {
+            self.n -= 1;
+        }
+        self.shrink();
+    }
+
+    /// Removes the smallest key and its value
+    pub fn delete_min(&mut self) {
+        self.accesses.set(0);
+        if self.is_empty() {
+            return;
+        }
+        delete_min(&mut self.root, self.m, &self.accesses);
+        self.n -= 1;
+        self.shrink();
+    }
+
+    /// Removes the largest key and its value
+    pub fn delete_max(&mut self) {
+        self.accesses.set(0);
+        if self.is_empty() {
+            return;
+        }
+        delete_max(&mut self.root, self.m, &self.accesses);
+        self.n -= 1;
+        self.shrink();
+    }
+
+    fn shrink(&mut self) {
+        if self.root.keys.is_empty() {
+            // the root lost its last key in a merge, so its only child becomes the root
+            if let Some(child) = self.root.children.pop() {
+                self.root = child;
+                self.height -= 1;
+            }
+        }
+    }
+}
+
+/// The fewest keys a node other than the root can have
+fn min_keys(m: usize) -> usize {
+    m.div_ceil(2) - 1
+}
+
+fn access(accesses: &Cell<usize>) {
+    accesses.set(accesses.get() + 1);
+}
+
+/// Inserts the key below node, and returns whether the key was new together with
+/// the middle key and the right half of node if it had to be split
+fn put<T:Ord,K>(node: &mut Node<T,K>, key: T, val: K, m: usize, accesses: &Cell<usize>) -> (bool, Split<T,K>) {
+    access(accesses);
+    let i = binary_search::rank(&node.keys, &key);
+    if i < node.keys.len() && node.keys[i] == key {
+        node.vals[i] = val;
+        return (false, None);
+    }
+    if node.is_leaf() {
+        node.keys.insert(i, key);
+        node.vals.insert(i, val);
+    } else {
+        let (added, split) = put(&mut node.children[i], key, val, m, accesses);
+        match split {
+            Some((key, val, right)) => {
+                node.keys.insert(i, key);
+                node.vals.insert(i, val);
+                node.children.insert(i + 1, right);
+            }
+            None => return (added, None),
+        }
+    }
+    if node.keys.len() < m {
+        return (true, None);
+    }
+
+    // the node has m keys, so it is split around its middle key
+    access(accesses);
+    let mid = node.keys.len() / 2;
+    let mut right = Node::new();
+    right.keys = node.keys.split_off(mid + 1);
+    right.vals = node.vals.split_off(mid + 1);
+    if !node.is_leaf() {
+        right.children = node.children.split_off(mid + 1);
+    }
+    let key = node.keys.pop().unwrap();
+    let val = node.vals.pop().unwrap();
+    (true, Some((key, val, right)))
+}
+
+/// Removes the key below node, and returns its key and value if it was there.
+/// The children of node are kept from having too few keys, but node itself might get too few
+fn delete<T:Ord,K>(node: &mut Node<T,K>, key: &T, m: usize, accesses: &Cell<usize>) -> Option<(T, K)> {
+    access(accesses);
+    let i = binary_search::rank(&node.keys, key);
+    let found = i < node.keys.len() && node.keys[i] == *key;
+    if node.is_leaf() {
+        if !found {
+            return None;
+        }
+        return Some((node.keys.remove(i), node.vals.remove(i)));
+    }
+    let removed = if found {
+        // the key takes the place of its predecessor, which is removed from the leaf it is in
+        let (key, val) = delete_max(&mut node.children[i], m, accesses);
+        let key = std::mem::replace(&mut node.keys[i], key);
+        let val = std::mem::replace(&mut node.vals[i], val);
+        Some((key, val))
+    } else {
+        delete(&mut node.children[i], key, m, accesses)
+    };
+    fix_child(node, i, m, accesses);
+    removed
+}
+
+/// Removes the largest key below node, which has to be non-empty
+fn delete_max<T,K>(node: &mut Node<T,K>, m: usize, accesses: &Cell<usize>) -> (T, K) {
+    access(accesses);
+    if node.is_leaf() {
+        return (node.keys.pop().unwrap(), node.vals.pop().unwrap());
+    }
+    let last = node.children.len() - 1;
+    let max = delete_max(&mut node.children[last], m, accesses);
+    fix_child(node, last, m, accesses);
+    max
+}
+
+/// Removes the smallest key below node, which has to be non-empty
+fn delete_min<T,K>(node: &mut Node<T,K>, m: usize, accesses: &Cell<usize>) -> (T, K) {
+    access(accesses);
+    if node.is_leaf() {
+        return (node.keys.remove(0), node.vals.remove(0));
+    }
+    let min = delete_min(&mut node.children[0], m, accesses);
+    fix_child(node, 0, m, accesses);
+    min
+}
+
+/// Gives child i of node enough keys again, by borrowing a key from a sibling through node or by merging it with a sibling
+fn fix_child<T,K>(node: &mut Node<T,K>, i: usize, m: usize, accesses: &Cell<usize>) {
+    if node.children[i].keys.len() >= min_keys(m) {
+        return;
+    }
+    if i > 0 {
+        access(accesses);
+        if node.children[i - 1].keys.len() > min_keys(m) {
+            let (left, right) = node.children.split_at_mut(i);
+            let left = &mut left[i - 1];
+            let child = &mut right[0];
+            // the largest key of the left sibling moves up, and the key between them moves down
+            let key = std::mem::replace(&mut node.keys[i - 1], left.keys.pop().unwrap());
+            let val = std::mem::replace(&mut node.vals[i - 1], left.vals.pop().unwrap());
+            child.keys.insert(0, key);
+            child.vals.insert(0, val);
+            if let Some(grandchild) = left.children.pop() {
+                child.children.insert(0, grandchild);
+            }
+            return;
+        }
+    }
+    if i + 1 < node.children.len() {
+        access(accesses);
+        if node.children[i + 1].keys.len() > min_keys(m) {
+            let (left, right) = node.children.split_at_mut(i + 1);
+            let child = &mut left[i];
+            let right = &mut right[0];
+            let key = std::mem::replace(&mut node.keys[i], right.keys.remove(0));
+            let val = std::mem::replace(&mut node.vals[i], right.vals.remove(0));
+            child.keys.push(key);
+            child.vals.push(val);
+            if !right.is_leaf() {
+                child.children.push(right.children.remove(0));
+            }
+            return;
+        }
+    }
+    // no sibling can spare a key, so the child is merged with one of them and the key between them
+    let j = if i > 0 { i - 1 } else { i };
+    let right = node.children.remove(j + 1);
+    let left = &mut node.children[j];
+    left.keys.push(node.keys.remove(j));
+    left.vals.push(node.vals.remove(j));
+    let Node { keys, vals, children } = right;
+    left.keys.extend(keys);
+    left.vals.extend(vals);
+    left.children.extend(children);
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::randomization::lcg_random::lcg_generate_seeded;
+
+    use super::{min_keys, BTree, Node};
+
+    /// Checks that the keys are in order and the amount of keys and children of every node, and returns the depth of the leaves
+    fn check<T:Ord,K>(node: &Node<T,K>, m: usize, is_root: bool, lo: Option<&T>, hi: Option<&T>) -> usize {
+        assert!(node.keys.len() < m);
+        assert!(is_root || node.keys.len() >= min_keys(m));
+        assert_eq!(node.keys.len(), node.vals.len());
+        assert!(node.keys.windows(2).all(|w| w[0] < w[1]));
+        assert!(node.keys.first().is_none_or(|first| lo.is_none_or(|lo| lo < first)));
+        assert!(node.keys.last().is_none_or(|last| hi.is_none_or(|hi| last < hi)));
+        if node.is_leaf() {
+            return 0;
+        }
+        assert!(!node.keys.is_empty());
+        assert_eq!(node.children.len(), node.keys.len() + 1);
+        let depths: Vec<usize> = node.children.iter().enumerate().map(|(i, child)| {
+            let lo = if i == 0 { lo } else { Some(&node.keys[i - 1]) };
+            let hi = if i == node.keys.len() { hi } else { Some(&node.keys[i]) };
+            check(child, m, false, lo, hi)
+        }).collect();
+        // every leaf is at the same depth
+        assert!(depths.iter().all(|d| *d == depths[0]));
+        depths[0] + 1
+    }
+
+    fn count<T,K>(node: &Node<T,K>) -> usize {
+        node.keys.len() + node.children.iter().map(|child| count(child)).sum::<usize>()
+    }
+
+    fn check_tree<T:Ord,K>(bt: &BTree<T,K>) {
+        assert_eq!(check(&bt.root, bt.order(), true, None, None) as isize, bt.height());
+        assert_eq!(count(&bt.root), bt.size());
+    }
+
+    #[test]
+    fn test_random_operations() {
+        let rand = lcg_generate_seeded(8000, 49);
+        for m in [3, 4, 5, 6, 7, 16] {
+            let mut bt: BTree<u32,usize> = BTree::with_order(m);
+            let mut expected: BTreeMap<u32,usize> = BTreeMap::new();
+            for (i, c) in rand.chunks(2).enumerate() {
+                let key = c[1] % 500;
+                // mostly puts at first, and mostly deletes at the end, so the tree both grows and shrinks
+                if (c[0] as usize % 4000) < 4000 - i {
+                    bt.put(key, i);
+                    expected.insert(key, i);
+                } else {
+                    bt.delete(&key);
+                    expected.remove(&key);
+                }
+                check_tree(&bt);
+                assert_eq!(bt.get(&key), expected.get(&key));
+            }
+            for key in 0..500 {
+                assert_eq!(bt.get(&key), expected.get(&key));
+            }
+            assert_eq!(bt.min(), expected.keys().next());
+            assert_eq!(bt.max(), expected.keys().next_back());
+        }
+    }
+
+    #[test]
+    fn test_keys_between() {
+        let mut bt: BTree<u32,()> = BTree::with_order(5);
+        let mut expected: BTreeMap<u32,()> = BTreeMap::new();
+        for r in lcg_generate_seeded(1000, 49) {
+            bt.put(r % 2000, ());
+            expected.insert(r % 2000, ());
+        }
+        let mut keys = bt.keys();
+        for key in expected.keys() {
+            assert_eq!(keys.dequeue(), Some(key));
+        }
+        assert!(keys.is_empty());
+        for (lo, hi) in [(0, 2000), (500, 1500), (700, 710), (1999, 2500), (3, 3)] {
+            let mut keys = bt.keys_between(&lo, &hi);
+            for key in expected.range(lo..=hi).map(|(k, _)| k) {
+                assert_eq!(keys.dequeue(), Some(key));
+            }
+            assert!(keys.is_empty());
+        }
+        let (lo, hi) = (1500, 500);
+        assert!(bt.keys_between(&lo, &hi).is_empty());
+
+        // a narrow range only reads the pages on the paths to its ends
+        let path = 2 * (bt.height() as usize + 1);
+        bt.keys_between(&700, &701);
+        assert!(bt.page_accesses() <= path);
+        bt.size_between(&700, &701);
+        assert!(bt.page_accesses() <= path);
+        bt.keys();
+        assert!(bt.page_accesses() > path);
+    }
+
+    #[test]
+    fn test_ordered_operations() {
+        let rand = lcg_generate_seeded(1000, 49);
+        for m in [3, 4, 7] {
+            let mut bt: BTree<u32,usize> = BTree::with_order(m);
+            let mut expected: BTreeMap<u32,usize> = BTreeMap::new();
+            for (i, r) in rand.iter().enumerate() {
+                bt.put(r % 2000, i);
+                expected.insert(r % 2000, i);
+            }
+            for key in (0..2010).step_by(7) {
+                assert_eq!(bt.floor(&key), expected.range(..=key).next_back().map(|(k, _)| k));
+                assert_eq!(bt.ceiling(&key), expected.range(key..).next().map(|(k, _)| k));
+                assert_eq!(bt.rank(&key), expected.range(..key).count());
+                assert_eq!(bt.size_between(&key, &(key + 300)), expected.range(key..=key + 300).count());
+            }
+            for (r, key) in expected.keys().enumerate() {
+                assert_eq!(bt.select(r), Some(key));
+            }
+            assert_eq!(bt.select(expected.len()), None);
+            assert_eq!(bt.size_between(&1500, &500), 0);
+
+            // the smallest and largest keys are taken off in turn until the tree is empty
+            for i in 0..expected.len() {
+                if i % 2 == 0 {
+                    bt.delete_min();
+                    expected.pop_first();
+                } else {
+                    bt.delete_max();
+                    expected.pop_last();
+                }
+                check_tree(&bt);
+                assert_eq!(bt.min(), expected.keys().next());
+                assert_eq!(bt.max(), expected.keys().next_back());
+            }
+            assert!(bt.is_empty());
+            bt.delete_min();
+            bt.delete_max();
+            assert_eq!((bt.size(), bt.height()), (0, 0));
+        }
+    }
+
+    #[test]
+    fn test_page_accesses() {
+        let mut small: BTree<u32,u32> = BTree::with_order(3);
+        let mut large: BTree<u32,u32> = BTree::with_order(64);
+        for i in 0..10_000 {
+            small.put(i, i);
+            large.put(i, i);
+        }
+        check_tree(&small);
+        check_tree(&large);
+        // larger pages give a lower tree, which is what bounds the cost of a lookup
+        assert!(large.height() < small.height());
+        assert!(small.height() <= 13 && large.height() <= 3);
+        for key in [0, 5000, 9999, 10_000] {
+            small.get(&key);
+            assert!(small.page_accesses() as isize <= small.height() + 1);
+            large.get(&key);
+            assert!(large.page_accesses() as isize <= large.height() + 1);
+        }
+        large.get(&20_000);
+        assert_eq!(large.page_accesses() as isize, large.height() + 1);
+        large.min();
+        assert_eq!(large.page_accesses() as isize, large.height() + 1);
+        large.floor(&20_000);
+        assert_eq!(large.page_accesses() as isize, large.height() + 1);
+    }
+
+    #[test]
+    fn test_delete_everything() {
+        let mut bt: BTree<u32,()> = BTree::with_order(3);
+        for i in 0..300 {
+            bt.put(i, ());
+        }
+        for i in (0..300).step_by(2).chain((1..300).step_by(2).rev()) {
+            bt.delete(&i);
+            check_tree(&bt);
+        }
+        assert!(bt.is_empty());
+        assert_eq!(bt.height(), 0);
+        assert_eq!(bt.min(), None);
+        bt.delete(&3);
+        assert!(bt.is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_too_small_order() {
+        BTree::<u32,()>::with_order(2);
+    }
+}
@@ -0,0 +1,218 @@
+use crate::fundamentals::{binary_search, queue::Queue};
+
+/// The BinarySearchST class represents an ordered symbol table of generic key value pairs,
+/// stored in two parallel arrays where the keys are kept sorted.
+/// It supports the operations `put`, `get`, `contains`, `delete`, `delete_max` and `delete_min`,
+/// together with the ordered operations `min`, `max`, `floor`, `ceiling`, `rank`, `select`, `keys_between` and `size_between`.
+///
+/// Everything is built on `rank`, which finds the amount of smaller keys with `binary_search::rank`, so a search takes *O(log N)* time.
+/// `put` and `delete` have to move the keys after the position to keep the arrays sorted, so they take *O(N)* time in the worst case.
+///
+/// For additional documentation, see Section 3.1 of Algorithms, 4th Edition by Robert Sedgewick and Kevin Wayne.
+///
+/// # Examples
+///
+/// ```
+/// use itualgs_rs::searching::binary_search_st::BinarySearchST;
+///
+/// let mut st: BinarySearchST<u32,&str> = BinarySearchST::new();
+/// st.put(4, "val4");
+/// st.put(10, "val10");
+/// st.put(2, "val2");
+///
+/// assert_eq!(st.get(&10), Some(&"val10"));
+/// assert_eq!(st.rank(&5), 2);
+/// assert_eq!(st.select(0), Some(&2));
+/// assert_eq!(st.ceiling(&5), Some(&10));
+///
+/// st.delete_min();
+/// assert_eq!(st.min(), Some(&4));
+/// ```
+pub struct BinarySearchST<T,K> {
+    keys: Vec<T>,
+    vals: Vec<K>,
+}
+
+impl<T,K> Default for BinarySearchST<T,K>
+    where T:Ord
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T,K> BinarySearchST<T,K>
+    where T:Ord
+{
+    pub fn new() -> Self {
+        BinarySearchST { keys: Vec::new(), vals: Vec::new() }
+    }
+
+    /// The amount of key value pairs
+    pub fn size(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// The amount of keys strictly smaller than key
+    pub fn rank(&self, key: &T) -> usize {
+        binary_search::rank(&self.keys, key)
+    }
+
+    /// The position of the key, if it is in the table
+    fn position(&self, key: &T) -> Option<usize> {
+        let i = self.rank(key);
+        if i < self.keys.len() && self.keys[i] == *key {
+            Some(i)
+        } else {
+            None
+        }
+    }
+
+    pub fn get(&self, key: &T) -> Option<&K> {
+        self.position(key).map(|i| &self.vals[i])
+    }
+
+    pub fn contains(&self, key: &T) -> bool {
+        self.position(key).is_some()
+    }
+
+    /// Inserts the key value pair, overwriting the value if the key is already in the table
+    pub fn put(&mut self, key: T, val: K) {
+        let i = self.rank(&key);
+        if i < self.keys.len() && self.keys[i] == key {
+            self.vals[i] = val;
+            return;
+        }
+        self.keys.insert(i, key);
+        self.vals.insert(i, val);
+    }
+
+    /// Removes the key and its value, if the key is in the table
+    pub fn delete(&mut self, key: &T) {
+        if let Some(i) = self.position(key) {
+            self.keys.remove(i);
+            self.vals.remove(i);
+        }
+    }
+
+    pub fn delete_min(&mut self) {
+        if !self.is_empty() {
+            self.keys.remove(0);
+            self.vals.remove(0);
+        }
+    }
+
+    pub fn delete_max(&mut self) {
+        self.keys.pop();
+        self.vals.pop();
+    }
+
+    /// The smallest key
+    pub fn min(&self) -> Option<&T> {
+        self.keys.first()
+    }
+
+    /// The largest key
+    pub fn max(&self) -> Option<&T> {
+        self.keys.last()
+    }
+
+    /// The key of rank r, which is the r+1'th smallest key
+    pub fn select(&self, r: usize) -> Option<&T> {
+        self.keys.get(r)
+    }
+
+    /// The largest key smaller than or equal to key
+    pub fn floor(&self, key: &T) -> Option<&T> {
+        match self.position(key) {
+            Some(i) => Some(&self.keys[i]),
+            None => self.rank(key).checked_sub(1).map(|i| &self.keys[i]),
+        }
+    }
+
+    /// The smallest key larger than or equal to key
+    pub fn ceiling(&self, key: &T) -> Option<&T> {
+        self.keys.get(self.rank(key))
+    }
+
+    /// All the keys, in order
+    pub fn keys(&self) -> Queue<&T> {
+        let mut queue = Queue::new();
+        for key in &self.keys {
+            queue.enqueue(key);
+        }
+        queue
+    }
+
+    /// The keys between lo and hi, both inclusive, in order
+    pub fn keys_between(&self, lo: &T, hi: &T) -> Queue<&T> {
+        let mut queue = Queue::new();
+        if lo > hi {
+            return queue;
+        }
+        for key in &self.keys[self.rank(lo)..self.rank(hi)] {
+            queue.enqueue(key);
+        }
+        if self.contains(hi) {
+            queue.enqueue(&self.keys[self.rank(hi)]);
+        }
+        queue
+    }
+
+    /// The amount of keys between lo and hi, both inclusive
+    pub fn size_between(&self, lo: &T, hi: &T) -> usize {
+        if lo > hi {
+            return 0;
+        }
+        let below_hi = self.rank(hi) + if self.contains(hi) { 1 } else { 0 };
+        below_hi - self.rank(lo)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::BinarySearchST;
+
+    #[test]
+    fn test_keys_stay_sorted() {
+        let mut st: BinarySearchST<char,usize> = BinarySearchST::new();
+        for (i, c) in "searchexample".chars().enumerate() {
+            st.put(c, i);
+        }
+        assert_eq!(st.size(), 10);
+        assert!(st.keys.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(st.get(&'e'), Some(&12));
+        assert_eq!(st.get(&'s'), Some(&0));
+
+        assert_eq!(st.floor(&'b'), Some(&'a'));
+        assert_eq!(st.floor(&'A'), None);
+        assert_eq!(st.ceiling(&'q'), Some(&'r'));
+        assert_eq!(st.ceiling(&'z'), None);
+        assert_eq!(st.size_between(&'e', &'p'), 5);
+        assert_eq!(st.keys_between(&'e', &'p').size(), 5);
+        assert_eq!(st.keys_between(&'p', &'e').size(), 0);
+
+        st.delete(&'m');
+        st.delete(&'q');
+        st.delete_max();
+        assert_eq!(st.max(), Some(&'s'));
+        assert_eq!(st.size(), 8);
+        assert_eq!(st.keys.len(), st.vals.len());
+    }
+
+    #[test]
+    fn test_empty() {
+        let mut st: BinarySearchST<u32,()> = BinarySearchST::new();
+        st.delete_min();
+        st.delete_max();
+        st.delete(&3);
+        assert!(st.is_empty());
+        assert_eq!(st.floor(&3), None);
+        assert_eq!(st.rank(&3), 0);
+    }
+}
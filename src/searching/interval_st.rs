@@ -0,0 +1,483 @@
+use std::cmp::Ordering::*;
+
+use crate::fundamentals::queue::Queue;
+use crate::randomization::lcg_random::LcgRandom;
+
+use super::search_tree::{self, height, rotate_left, rotate_right, size, TreeNode};
+
+/// The Interval class represents a closed interval [lo, hi] of generic endpoints.
+/// Intervals are ordered by their start, and intervals with the same start by their end,
+/// which is the order an `IntervalST` keeps them in.
+///
+/// # Examples
+///
+/// ```
+/// use itualgs_rs::searching::interval_st::Interval;
+///
+/// let morning = Interval::new(9, 12);
+/// assert!(morning.intersects(&Interval::new(12, 13)));
+/// assert!(!morning.intersects(&Interval::new(13, 17)));
+/// assert!(morning.contains(&10));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Interval<T> {
+    lo: T,
+    hi: T,
+}
+
+impl<T> Interval<T>
+    where T:Ord
+{
+    pub fn new(lo: T, hi: T) -> Self {
+        if lo > hi {
+            panic!("The start of an interval can not be after its end!")
+        }
+        Interval { lo, hi }
+    }
+
+    /// The start of the interval
+    pub fn lo(&self) -> &T {
+        &self.lo
+    }
+
+    /// The end of the interval
+    pub fn hi(&self) -> &T {
+        &self.hi
+    }
+
+    /// Whether the two intervals have a point in common
+    pub fn intersects(&self, other: &Interval<T>) -> bool {
+        self.lo <= other.hi && other.lo <= self.hi
+    }
+
+    /// Whether the point is in the interval
+    pub fn contains(&self, point: &T) -> bool {
+        self.lo <= *point && *point <= self.hi
+    }
+}
+
+/// The IntervalST class represents a symbol table of intervals with generic values,
+/// stored in a binary search tree keyed on the intervals, where every node also keeps the largest end of an interval in its subtree.
+/// It supports the operations `put`, `get`, `contains`, `delete`, `size` and `height`,
+/// the interval search operations `intersects`, which finds any interval that intersects a given interval, and `all_intersecting`,
+/// and the 1D range search operation `starting_between`, which finds the intervals that start in a range.
+///
+/// The largest end in a subtree tells the searches which subtrees can hold an intersecting interval:
+/// if the left subtree ends before the query starts the search goes right, and otherwise the left subtree has an intersecting interval
+/// unless every interval in it starts after the query, and then so does every interval in the right subtree.
+/// So `intersects` follows a single path and takes time proportional to the height, and `all_intersecting` takes time proportional to *R log N* for R intervals found.
+///
+/// The tree is a randomized binary search tree, where a new interval becomes the root of a subtree of N nodes with probability 1/(N+1)
+/// and a deleted node is replaced by a random join of its subtrees, using an `LcgRandom`.
+/// The tree then has the shape of a binary search tree built from the intervals in a random order,
+/// so the expected height is *O(log N)* also when the intervals are inserted in order, which a calendar usually does.
+/// The nodes are laid out like the nodes of `BST`, but the tree does not reuse `BST`, since its shape follows the insertion order
+/// and sorted inserts would turn it into a path with *O(N)* searches.
+///
+/// For additional documentation, see the Geometric Applications of BSTs lecture accompanying Algorithms, 4th Edition by Robert Sedgewick and Kevin Wayne.
+///
+/// # Examples
+///
+/// ```
+/// use itualgs_rs::searching::interval_st::{Interval, IntervalST};
+///
+/// let mut calendar: IntervalST<u32,&str> = IntervalST::new();
+/// calendar.put(Interval::new(9, 10), "standup");
+/// calendar.put(Interval::new(13, 15), "review");
+/// calendar.put(Interval::new(14, 16), "lecture");
+///
+/// let meeting = Interval::new(10, 11);
+/// assert_eq!(calendar.intersects(&meeting), Some(&Interval::new(9, 10)));
+/// assert_eq!(calendar.intersects(&Interval::new(11, 12)), None);
+/// assert_eq!(calendar.all_intersecting(&Interval::new(15, 17)).size(), 2);
+/// assert_eq!(calendar.starting_between(&12, &14).size(), 2);
+///
+/// calendar.delete(&Interval::new(9, 10));
+/// assert_eq!(calendar.intersects(&meeting), None);
+/// ```
+pub struct IntervalST<T,K> {
+    root: Edge<T,K>,
+    random: LcgRandom,
+}
+
+struct Value<T,K> {
+    key: Interval<T>,
+    val: K,
+}
+
+type Edge<T,K> = Option<Box<Node<T,K>>>;
+
+struct Node<T,K> {
+    value: Value<T,K>,
+    left: Edge<T,K>,
+    right: Edge<T,K>,
+    // the amount of nodes in the subtree rooted here
+    size: usize,
+    // the largest end of an interval in the subtree rooted here
+    max: T,
+}
+
+impl<T,K> Node<T,K>
+    where T:Ord + Clone
+{
+    fn new(key: Interval<T>, val: K) -> Box<Self> {
+        let max = key.hi.clone();
+        Box::new(Node { value: Value { key, val }, left: None, right: None, size: 1, max })
+    }
+}
+
+impl<T,K> TreeNode for Node<T,K>
+    where T:Ord + Clone
+{
+    type Key = Interval<T>;
+    type Val = K;
+
+    fn key(&self) -> &Interval<T> {
+        &self.value.key
+    }
+
+    fn val(&self) -> &K {
+        &self.value.val
+    }
+
+    fn left(&self) -> &Edge<T,K> {
+        &self.left
+    }
+
+    fn right(&self) -> &Edge<T,K> {
+        &self.right
+    }
+
+    fn left_mut(&mut self) -> &mut Edge<T,K> {
+        &mut self.left
+    }
+
+    fn right_mut(&mut self) -> &mut Edge<T,K> {
+        &mut self.right
+    }
+
+    fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Recomputes the size and the largest end of the subtree from the children
+    fn update(&mut self) {
+        self.size = 1 + size(&self.left) + size(&self.right);
+        let mut max = &self.value.key.hi;
+        for child in [&self.left, &self.right].into_iter().flatten() {
+            if child.max > *max {
+                max = &child.max;
+            }
+        }
+        self.max = max.clone();
+    }
+}
+
+impl<T,K> Default for IntervalST<T,K>
+    where T:Ord + Clone
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T,K> IntervalST<T,K>
+    where T:Ord + Clone
+{
+    /// Creates an empty table, whose random choices are seeded with the current system time
+    pub fn new() -> Self {
+        IntervalST { root: None, random: LcgRandom::new() }
+    }
+
+    /// Creates an empty table, whose random choices are seeded with the given seed, so the shape of the tree can be reproduced
+    pub fn with_seed(seed: u64) -> Self {
+        IntervalST { root: None, random: LcgRandom::with_seed(seed) }
+    }
+
+    /// The amount of intervals
+    pub fn size(&self) -> usize {
+        size(&self.root)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    /// The height of the tree, where a tree with a single node has height 0 and the empty tree has height -1
+    pub fn height(&self) -> isize {
+        height(&self.root)
+    }
+
+    /// The value of the interval
+    pub fn get(&self, key: &Interval<T>) -> Option<&K> {
+        search_tree::get(&self.root, key)
+    }
+
+    pub fn contains(&self, key: &Interval<T>) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Inserts the interval and its value, overwriting the value if the interval is already in the table
+    pub fn put(&mut self, key: Interval<T>, val: K) {
+        let mut curr = &mut self.root;
+        while let Some(node) = curr {
+            match key.cmp(&node.value.key) {
+                Less => curr = &mut node.left,
+                Greater => curr = &mut node.right,
+                Equal => {
+                    node.value.val = val;
+                    return;
+                }
+            }
+        }
+        self.root = Some(put(self.root.take(), key, val, &mut self.random));
+    }
+
+    /// Removes the interval and its value, if the interval is in the table
+    pub fn delete(&mut self, key: &Interval<T>) {
+        self.root = delete(self.root.take(), key, &mut self.random);
+    }
+
+    /// Any interval in the table that intersects the interval
+    pub fn intersects(&self, interval: &Interval<T>) -> Option<&Interval<T>> {
+        let mut curr = &self.root;
+        while let Some(node) = curr {
+            if node.value.key.intersects(interval) {
+                return Some(&node.value.key);
+            }
+            // an intersecting interval on the left has to end at or after the query starts
+            curr = match &node.left {
+                Some(left) if left.max >= interval.lo => &node.left,
+                _ => &node.right,
+            };
+        }
+        None
+    }
+
+    /// All the intervals in the table that intersect the interval, in order
+    pub fn all_intersecting(&self, interval: &Interval<T>) -> Queue<&Interval<T>> {
+        let mut queue = Queue::new();
+        all_intersecting(&self.root, interval, &mut queue);
+        queue
+    }
+
+    /// The intervals which start between lo and hi, both inclusive, in order
+    pub fn starting_between(&self, lo: &T, hi: &T) -> Queue<&Interval<T>> {
+        let mut queue = Queue::new();
+        starting_between(&self.root, lo, hi, &mut queue);
+        queue
+    }
+
+    /// All the intervals, in order
+    pub fn keys(&self) -> Queue<&Interval<T>> {
+        search_tree::keys(&self.root)
+    }
+}
+
+/// Inserts an interval which is not below node, as the root of the subtree with probability 1/(N+1)
+fn put<T:Ord + Clone,K>(node: Edge<T,K>, key: Interval<T>, val: K, random: &mut LcgRandom) -> Box<Node<T,K>> {
+    let mut x = match node {
+        None => return Node::new(key, val),
+        Some(x) => x,
+    };
+    if random.uniform(x.size + 1) == 0 {
+        return root_put(Some(x), key, val);
+    }
+    if key < x.value.key {
+        x.left = Some(put(x.left.take(), key, val, random));
+    } else {
+        x.right = Some(put(x.right.take(), key, val, random));
+    }
+    x.update();
+    x
+}
+
+/// Inserts an interval which is not below node as a leaf, and rotates it up to the root of the subtree
+fn root_put<T:Ord + Clone,K>(node: Edge<T,K>, key: Interval<T>, val: K) -> Box<Node<T,K>> {
+    let mut x = match node {
+        None => return Node::new(key, val),
+        Some(x) => x,
+    };
+    if key < x.value.key {
+        x.left = Some(root_put(x.left.take(), key, val));
+        rotate_right(x)
+    } else {
+        x.right = Some(root_put(x.right.take(), key, val));
+        rotate_left(x)
+    }
+}
+
+fn delete<T:Ord + Clone,K>(node: Edge<T,K>, key: &Interval<T>, random: &mut LcgRandom) -> Edge<T,K> {
+    let mut x = node?;
+    match key.cmp(&x.value.key) {
+        Less => x.left = delete(x.left.take(), key, random),
+        Greater => x.right = delete(x.right.take(), key, random),
+        Equal => return join(x.left.take(), x.right.take(), random),
+    }
+    x.update();
+    Some(x)
+}
+
+/// Joins two trees, where every interval in a is smaller than every interval in b.
+/// The root of a becomes the root with probability size(a) / (size(a) + size(b))
+fn join<T:Ord + Clone,K>(a: Edge<T,K>, b: Edge<T,K>, random: &mut LcgRandom) -> Edge<T,K> {
+    let (mut a, mut b) = match (a, b) {
+        (None, b) => return b,
+        (a, None) => return a,
+        (Some(a), Some(b)) => (a, b),
+    };
+    if random.uniform(a.size + b.size) < a.size {
+        a.right = join(a.right.take(), Some(b), random);
+        a.update();
+        Some(a)
+    } else {
+        b.left = join(Some(a), b.left.take(), random);
+        b.update();
+        Some(b)
+    }
+}
+
+/// Adds the intervals below node that intersect the interval to the queue in order, and returns whether there were any
+fn all_intersecting<'a,T:Ord,K>(node: &'a Edge<T,K>, interval: &Interval<T>, queue: &mut Queue<&'a Interval<T>>) -> bool {
+    let x = match node {
+        None => return false,
+        Some(x) => x,
+    };
+    let search_left = x.left.as_ref().is_some_and(|left| left.max >= interval.lo);
+    let found_left = search_left && all_intersecting(&x.left, interval, queue);
+    let found = x.value.key.intersects(interval);
+    if found {
+        queue.enqueue(&x.value.key);
+    }
+    // when the left subtree reaches the query but has no intersecting interval, its intervals start after the query, and so do the ones on the right
+    let search_right = (found_left || !search_left) && x.value.key.lo <= interval.hi;
+    let found_right = search_right && all_intersecting(&x.right, interval, queue);
+    found_left || found || found_right
+}
+
+/// Adds the intervals below node which start between lo and hi (both inclusive) to the queue, in order
+fn starting_between<'a,T:Ord,K>(node: &'a Edge<T,K>, lo: &T, hi: &T, queue: &mut Queue<&'a Interval<T>>) {
+    if let Some(x) = node {
+        let start = &x.value.key.lo;
+        // intervals with the same start can be on both sides, since they are ordered by their end
+        if lo <= start {
+            starting_between(&x.left, lo, hi, queue);
+        }
+        if lo <= start && start <= hi {
+            queue.enqueue(&x.value.key);
+        }
+        if start <= hi {
+            starting_between(&x.right, lo, hi, queue);
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::randomization::lcg_random::LcgRandom;
+
+    use super::{size, Edge, Interval, IntervalST};
+
+    /// Checks the order of the intervals, the sizes and the largest ends, and returns the largest end
+    fn check<T:Ord + Clone,K>(node: &Edge<T,K>, lo: Option<&Interval<T>>, hi: Option<&Interval<T>>) -> Option<T> {
+        let x = node.as_ref()?;
+        assert!(lo.is_none_or(|lo| *lo < x.value.key));
+        assert!(hi.is_none_or(|hi| x.value.key < *hi));
+        assert_eq!(x.size, 1 + size(&x.left) + size(&x.right));
+        let left = check(&x.left, lo, Some(&x.value.key));
+        let right = check(&x.right, Some(&x.value.key), hi);
+        let max = [left, right, Some(x.value.key.hi.clone())].into_iter().flatten().max();
+        assert!(max.as_ref() == Some(&x.max));
+        max
+    }
+
+    fn random_interval(random: &mut LcgRandom) -> Interval<u32> {
+        let lo = random.uniform(1000) as u32;
+        Interval::new(lo, lo + random.uniform(50) as u32)
+    }
+
+    #[test]
+    fn test_same_as_linear_scan() {
+        let mut random = LcgRandom::with_seed(11);
+        let mut st: IntervalST<u32,usize> = IntervalST::with_seed(5);
+        let mut expected: Vec<Interval<u32>> = Vec::new();
+        for i in 0..3000 {
+            let interval = random_interval(&mut random);
+            if random.uniform(3) == 0 {
+                st.delete(&interval);
+                expected.retain(|e| *e != interval);
+            } else {
+                st.put(interval, i);
+                if !expected.contains(&interval) {
+                    expected.push(interval);
+                }
+            }
+            check(&st.root, None, None);
+            assert_eq!(st.size(), expected.len());
+
+            let query = random_interval(&mut random);
+            expected.sort();
+            let intersecting: Vec<&Interval<u32>> = expected.iter().filter(|e| e.intersects(&query)).collect();
+            match st.intersects(&query) {
+                Some(found) => assert!(found.intersects(&query) && st.contains(found)),
+                None => assert!(intersecting.is_empty()),
+            }
+            let mut all = st.all_intersecting(&query);
+            for e in intersecting {
+                assert_eq!(all.dequeue(), Some(e));
+            }
+            assert!(all.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_starting_between() {
+        let mut st: IntervalST<u32,()> = IntervalST::with_seed(50);
+        for lo in 0..50 {
+            for len in [0, 3, 10] {
+                st.put(Interval::new(lo, lo + len), ());
+            }
+        }
+        let mut found = st.starting_between(&10, &12);
+        for lo in 10..=12 {
+            for len in [0, 3, 10] {
+                assert_eq!(found.dequeue(), Some(&Interval::new(lo, lo + len)));
+            }
+        }
+        assert!(found.is_empty());
+        assert!(st.starting_between(&12, &10).is_empty());
+        assert_eq!(st.starting_between(&0, &100).size(), st.size());
+        assert_eq!(st.keys().size(), 150);
+    }
+
+    #[test]
+    fn test_sorted_inserts() {
+        let mut st: IntervalST<u32,u32> = IntervalST::with_seed(3);
+        // back to back meetings, inserted in order like a calendar would
+        for i in 0..2000 {
+            st.put(Interval::new(2 * i, 2 * i + 1), i);
+        }
+        check(&st.root, None, None);
+        assert!(st.height() < 60);
+        assert_eq!(st.get(&Interval::new(10, 11)), Some(&5));
+        assert_eq!(st.intersects(&Interval::new(4001, 4003)), None);
+        assert_eq!(st.intersects(&Interval::new(3998, 4100)), Some(&Interval::new(3998, 3999)));
+        assert_eq!(st.all_intersecting(&Interval::new(11, 16)).size(), 4);
+
+        st.put(Interval::new(10, 11), 100);
+        assert_eq!(st.get(&Interval::new(10, 11)), Some(&100));
+        assert_eq!(st.size(), 2000);
+        for i in 0..2000 {
+            st.delete(&Interval::new(2 * i, 2 * i + 1));
+        }
+        assert!(st.is_empty());
+        assert_eq!(st.intersects(&Interval::new(0, 10)), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_inverted_interval() {
+        Interval::new(3, 2);
+    }
+}
@@ -0,0 +1,251 @@
+use std::{collections::hash_map::RandomState, hash::{BuildHasher, Hash}};
+
+use crate::fundamentals::queue::Queue;
+
+const INIT_CAPACITY: usize = 4;
+
+/// The LinearProbingHashST class represents an unordered symbol table of generic key value pairs,
+/// stored in a hash table with open addressing. A key is placed in the first free slot at or after the slot it hashes to,
+/// so keys that collide form a cluster of consecutive slots.
+/// It supports the operations `put`, `get`, `contains`, `delete`, `size` and `keys`.
+///
+/// The table is doubled when it gets half full and halved when it is an eighth full, so the clusters stay short
+/// and with a hash function that spreads the keys evenly every operation takes constant amortized time.
+/// A delete can not just empty a slot, since that would cut the cluster in two and hide the keys after it,
+/// so the rest of the cluster is inserted again.
+/// The hash function comes from a `BuildHasher`, which is `RandomState` unless another one is given with `with_hasher`.
+///
+/// For additional documentation, see Section 3.4 of Algorithms, 4th Edition by Robert Sedgewick and Kevin Wayne.
+///
+/// # Examples
+///
+/// ```
+/// use itualgs_rs::searching::linear_probing_hash_st::LinearProbingHashST;
+///
+/// let mut st: LinearProbingHashST<&str,u32> = LinearProbingHashST::new();
+/// st.put("Ferris", 1);
+/// st.put("John", 2);
+/// st.put("Ferris", 3);
+///
+/// assert_eq!(st.get(&"Ferris"), Some(&3));
+/// assert_eq!(st.size(), 2);
+///
+/// st.delete(&"John");
+/// assert!(!st.contains(&"John"));
+/// ```
+pub struct LinearProbingHashST<T,K,S = RandomState> {
+    n: usize,
+    slots: Vec<Option<(T,K)>>,
+    hasher: S,
+}
+
+impl<T,K> LinearProbingHashST<T,K>
+    where T:Hash + Eq
+{
+    pub fn new() -> Self {
+        Self::with_hasher(RandomState::new())
+    }
+}
+
+impl<T,K> Default for LinearProbingHashST<T,K>
+    where T:Hash + Eq
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T,K,S> LinearProbingHashST<T,K,S>
+    where T:Hash + Eq, S:BuildHasher
+{
+    /// Creates an empty table, which hashes the keys with the given hasher
+    pub fn with_hasher(hasher: S) -> Self {
+        Self::with_capacity_and_hasher(INIT_CAPACITY, hasher)
+    }
+
+    /// Creates an empty table with m slots, which hashes the keys with the given hasher
+    pub fn with_capacity_and_hasher(m: usize, hasher: S) -> Self {
+        let slots = (0..m.max(INIT_CAPACITY)).map(|_| None).collect();
+        LinearProbingHashST { n: 0, slots, hasher }
+    }
+
+    /// The amount of key value pairs
+    pub fn size(&self) -> usize {
+        self.n
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+
+    /// The amount of slots
+    pub fn capacity(&self) -> usize {
+        self.slots.len()
+    }
+
+    pub fn get(&self, key: &T) -> Option<&K> {
+        let i = self.find(key)?;
+        self.slots[i].as_ref().map(|(_, v)| v)
+    }
+
+    pub fn contains(&self, key: &T) -> bool {
+        self.find(key).is_some()
+    }
+
+    /// Inserts the key value pair, overwriting the value if the key is already in the table
+    pub fn put(&mut self, key: T, val: K) {
+        if let Some(i) = self.find(&key) {
+            self.slots[i] = Some((key, val));
+            return;
+        }
+        if 2 * (self.n + 1) > self.slots.len() {
+            self.resize(2 * self.slots.len());
+        }
+        self.insert(key, val);
+        self.n += 1;
+    }
+
+    /// Removes the key and its value, if the key is in the table
+    pub fn delete(&mut self, key: &T) {
+        let mut i = match self.find(key) {
+            Some(i) => i,
+            None => return,
+        };
+        self.slots[i] = None;
+        self.n -= 1;
+
+        // the keys after the hole might have been placed past it, so they are inserted again
+        i = (i + 1) % self.slots.len();
+        while let Some((k, v)) = self.slots[i].take() {
+            self.insert(k, v);
+            i = (i + 1) % self.slots.len();
+        }
+
+        if self.slots.len() > INIT_CAPACITY && 8 * self.n <= self.slots.len() {
+            self.resize(self.slots.len() / 2);
+        }
+    }
+
+    /// All the keys, in no particular order
+    pub fn keys(&self) -> Queue<&T> {
+        let mut queue = Queue::new();
+        for (key, _) in self.slots.iter().flatten() {
+            queue.enqueue(key);
+        }
+        queue
+    }
+
+    fn hash(&self, key: &T) -> usize {
+        (self.hasher.hash_one(key) % self.slots.len() as u64) as usize
+    }
+
+    /// The slot holding the key, found by probing from its hash until an empty slot
+    fn find(&self, key: &T) -> Option<usize> {
+        let mut i = self.hash(key);
+        while let Some((k, _)) = &self.slots[i] {
+            if k == key {
+                return Some(i);
+            }
+            i = (i + 1) % self.slots.len();
+        }
+        None
+    }
+
+    /// Places a key which is not in the table in the first empty slot from its hash, there has to be one
+    fn insert(&mut self, key: T, val: K) {
+        let mut i = self.hash(&key);
+        while self.slots[i].is_some() {
+            i = (i + 1) % self.slots.len();
+        }
+        self.slots[i] = Some((key, val));
+    }
+
+    /// Moves every pair to a table of m slots
+    fn resize(&mut self, m: usize) {
+        let old = std::mem::replace(&mut self.slots, (0..m).map(|_| None).collect());
+        for (key, val) in old.into_iter().flatten() {
+            self.insert(key, val);
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::hash::{BuildHasher, BuildHasherDefault, Hasher};
+
+    use crate::randomization::lcg_random::lcg_generate_seeded;
+
+    use super::{LinearProbingHashST, INIT_CAPACITY};
+
+    /// A hasher that sends every key to the same slot, so all the keys are in one cluster
+    #[derive(Default)]
+    struct ConstantHasher;
+
+    impl Hasher for ConstantHasher {
+        fn finish(&self) -> u64 {
+            0
+        }
+
+        fn write(&mut self, _: &[u8]) {}
+    }
+
+    /// Checks the load factor, and that no key is separated from its hash by an empty slot
+    fn check_load<S: BuildHasher>(st: &LinearProbingHashST<u32,usize,S>) {
+        let m = st.capacity();
+        assert!(m >= INIT_CAPACITY);
+        assert!(2 * st.size() <= m);
+        assert!(m == INIT_CAPACITY || 8 * st.size() > m);
+        assert_eq!(st.slots.iter().flatten().count(), st.size());
+        for (i, slot) in st.slots.iter().enumerate() {
+            if let Some((key, _)) = slot {
+                let mut j = st.hash(key);
+                while j != i {
+                    assert!(st.slots[j].is_some());
+                    j = (j + 1) % m;
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_same_as_hashmap() {
+        let rand = lcg_generate_seeded(8000, 44);
+        let mut st: LinearProbingHashST<u32,usize> = LinearProbingHashST::new();
+        let mut expected: HashMap<u32,usize> = HashMap::new();
+        for (i, c) in rand.chunks(2).enumerate() {
+            let key = c[1] % 1000;
+            // mostly puts at first, and mostly deletes at the end, so the table both grows and shrinks
+            if (c[0] as usize % 4000) < 4000 - i {
+                st.put(key, i);
+                expected.insert(key, i);
+            } else {
+                st.delete(&key);
+                expected.remove(&key);
+            }
+            check_load(&st);
+            assert_eq!(st.size(), expected.len());
+            assert_eq!(st.get(&key), expected.get(&key));
+        }
+        assert_eq!(st.keys().size(), expected.len());
+        for key in 0..1000 {
+            assert_eq!(st.get(&key), expected.get(&key));
+        }
+    }
+
+    #[test]
+    fn test_delete_inside_cluster() {
+        let mut st: LinearProbingHashST<u32,usize,_> = LinearProbingHashST::with_hasher(BuildHasherDefault::<ConstantHasher>::default());
+        for key in 0..100 {
+            st.put(key, key as usize);
+        }
+        for key in (0..100).step_by(3) {
+            st.delete(&key);
+            check_load(&st);
+        }
+        for key in 0..100 {
+            assert_eq!(st.get(&key).copied(), if key % 3 != 0 { Some(key as usize) } else { None });
+        }
+    }
+}
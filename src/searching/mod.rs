@@ -1 +1,12 @@
-pub mod BST;
\ No newline at end of file
+pub mod BST;
+pub mod red_black_bst;
+pub mod separate_chaining_hash_st;
+pub mod linear_probing_hash_st;
+pub mod symbol_table;
+pub mod sequential_search_st;
+pub mod binary_search_st;
+pub mod avl_tree;
+pub mod treap;
+pub mod b_tree;
+pub mod interval_st;
+mod search_tree;
\ No newline at end of file
@@ -0,0 +1,455 @@
+use std::cmp::Ordering::*;
+
+use crate::fundamentals::queue::Queue;
+
+use super::search_tree::{self, height, size, TreeNode};
+
+/// The RedBlackBST class represents an ordered symbol table of generic key value pairs,
+/// stored in a left-leaning red-black binary search tree.
+/// It supports the operations `put`, `get`, `contains`, `delete`, `delete_max` and `delete_min`, together with `size` and `height`,
+/// and the ordered operations `min`, `max`, `floor`, `ceiling`, `rank`, `select`, `keys_between` and `size_between`.
+///
+/// A left-leaning red-black tree is a binary search tree representation of a 2-3 tree, where a red link glues two nodes together into a 3-node.
+/// Red links lean left, no node has two red links, and every path from the root to a null link has the same amount of black links.
+/// This keeps the height below *2 log N*, so every operation takes *O(log N)* time in the worst case, also when the keys are inserted in order.
+///
+/// For additional documentation, see Section 3.3 of Algorithms, 4th Edition by Robert Sedgewick and Kevin Wayne.
+///
+/// # Examples
+///
+/// ```
+/// use itualgs_rs::searching::red_black_bst::RedBlackBST;
+///
+/// let mut rb: RedBlackBST<u32,&str> = RedBlackBST::new();
+/// for i in 0..1000 {
+///     rb.put(i, "val");
+/// }
+/// rb.put(4, "val4");
+///
+/// assert_eq!(rb.get(&4).unwrap(), &"val4");
+/// assert_eq!(rb.size(), 1000);
+/// assert!(rb.height() <= 20);
+///
+/// rb.delete(&4);
+/// rb.delete_min();
+/// assert!(!rb.contains(&4));
+/// assert!(!rb.contains(&0));
+/// assert_eq!(rb.min(), Some(&1));
+/// assert_eq!(rb.floor(&4), Some(&3));
+/// assert_eq!(rb.rank(&10), 8);
+/// ```
+pub struct RedBlackBST<T,K> {
+    root: Link<T,K>,
+}
+
+type Link<T,K> = Option<Box<Node<T,K>>>;
+
+const RED: bool = true;
+const BLACK: bool = false;
+
+struct Node<T,K> {
+    key: T,
+    val: K,
+    left: Link<T,K>,
+    right: Link<T,K>,
+    // the color of the link from the parent
+    color: bool,
+    size: usize,
+}
+
+impl<T,K> TreeNode for Node<T,K> {
+    type Key = T;
+    type Val = K;
+
+    fn key(&self) -> &T {
+        &self.key
+    }
+
+    fn val(&self) -> &K {
+        &self.val
+    }
+
+    fn left(&self) -> &Link<T,K> {
+        &self.left
+    }
+
+    fn right(&self) -> &Link<T,K> {
+        &self.right
+    }
+
+    fn left_mut(&mut self) -> &mut Link<T,K> {
+        &mut self.left
+    }
+
+    fn right_mut(&mut self) -> &mut Link<T,K> {
+        &mut self.right
+    }
+
+    fn size(&self) -> usize {
+        self.size
+    }
+
+    fn update(&mut self) {
+        self.size = 1 + size(&self.left) + size(&self.right);
+    }
+}
+
+impl<T,K> Default for RedBlackBST<T,K>
+    where T:Ord
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T,K> RedBlackBST<T,K>
+    where T:Ord
+{
+    pub fn new() -> Self {
+        RedBlackBST { root: None }
+    }
+
+    /// The amount of key value pairs
+    pub fn size(&self) -> usize {
+        size(&self.root)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    /// The height of the tree, where a tree with a single node has height 0 and the empty tree has height -1
+    pub fn height(&self) -> isize {
+        height(&self.root)
+    }
+
+    pub fn get(&self, key: &T) -> Option<&K> {
+        search_tree::get(&self.root, key)
+    }
+
+    pub fn contains(&self, key: &T) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// The smallest key
+    pub fn min(&self) -> Option<&T> {
+        search_tree::min(&self.root)
+    }
+
+    /// The largest key
+    pub fn max(&self) -> Option<&T> {
+        search_tree::max(&self.root)
+    }
+
+    /// The largest key smaller than or equal to key
+    pub fn floor(&self, key: &T) -> Option<&T> {
+        search_tree::floor(&self.root, key)
+    }
+
+    /// The smallest key larger than or equal to key
+    pub fn ceiling(&self, key: &T) -> Option<&T> {
+        search_tree::ceiling(&self.root, key)
+    }
+
+    /// The amount of keys strictly smaller than key
+    pub fn rank(&self, key: &T) -> usize {
+        search_tree::rank(&self.root, key)
+    }
+
+    /// The key of rank r, which is the r+1'th smallest key
+    pub fn select(&self, r: usize) -> Option<&T> {
+        search_tree::select(&self.root, r)
+    }
+
+    /// All the keys, in order
+    pub fn keys(&self) -> Queue<&T> {
+        search_tree::keys(&self.root)
+    }
+
+    /// The keys between lo and hi, both inclusive, in order
+    pub fn keys_between(&self, lo: &T, hi: &T) -> Queue<&T> {
+        search_tree::keys_between(&self.root, lo, hi)
+    }
+
+    /// The amount of keys between lo and hi, both inclusive
+    pub fn size_between(&self, lo: &T, hi: &T) -> usize {
+        search_tree::size_between(&self.root, lo, hi)
+    }
+
+    /// Inserts the key value pair, overwriting the value if the key is already in the table
+    pub fn put(&mut self, key: T, val: K) {
+        let mut root = put(self.root.take(), key, val);
+        root.color = BLACK;
+        self.root = Some(root);
+    }
+
+    pub fn delete_min(&mut self) {
+        if let Some(mut root) = self.root.take() {
+            // the root is made red when both children are black, so the red link can be pushed down
+            if !is_red(&root.left) && !is_red(&root.right) {
+                root.color = RED;
+            }
+            self.root = delete_min(root).0;
+            self.blacken_root();
+        }
+    }
+
+    pub fn delete_max(&mut self) {
+        if let Some(mut root) = self.root.take() {
+            if !is_red(&root.left) && !is_red(&root.right) {
+                root.color = RED;
+            }
+            self.root = delete_max(root);
+            self.blacken_root();
+        }
+    }
+
+    /// Removes the key and its value, if the key is in the table
+    pub fn delete(&mut self, key: &T) {
+        if !self.contains(key) {
+            return;
+        }
+        let mut root = self.root.take().unwrap();
+        if !is_red(&root.left) && !is_red(&root.right) {
+            root.color = RED;
+        }
+        self.root = delete(root, key);
+        self.blacken_root();
+    }
+
+    fn blacken_root(&mut self) {
+        if let Some(root) = self.root.as_mut() {
+            root.color = BLACK;
+        }
+    }
+}
+
+fn is_red<T,K>(link: &Link<T,K>) -> bool {
+    link.as_ref().is_some_and(|node| node.color == RED)
+}
+
+fn put<T:Ord,K>(link: Link<T,K>, key: T, val: K) -> Box<Node<T,K>> {
+    let mut h = match link {
+        None => return Box::new(Node { key, val, left: None, right: None, color: RED, size: 1 }),
+        Some(h) => h,
+    };
+    match key.cmp(&h.key) {
+        Less => h.left = Some(put(h.left.take(), key, val)),
+        Greater => h.right = Some(put(h.right.take(), key, val)),
+        Equal => h.val = val,
+    }
+    balance(h)
+}
+
+/// Removes the smallest node below h, and returns what is left together with the removed node
+fn delete_min<T,K>(mut h: Box<Node<T,K>>) -> (Link<T,K>, Box<Node<T,K>>) {
+    if h.left.is_none() {
+        // a node without a left child has no right child either, since the tree is balanced
+        return (None, h);
+    }
+    if !is_red(&h.left) && !is_red(&h.left.as_ref().unwrap().left) {
+        h = move_red_left(h);
+    }
+    let (left, min) = delete_min(h.left.take().unwrap());
+    h.left = left;
+    (Some(balance(h)), min)
+}
+
+fn delete_max<T,K>(mut h: Box<Node<T,K>>) -> Link<T,K> {
+    if is_red(&h.left) {
+        h = rotate_right(h);
+    }
+    // the largest node has no right child, and it is removed by returning None
+    if !is_red(&h.right) && !is_red(&h.right.as_ref()?.left) {
+        h = move_red_right(h);
+    }
+    h.right = delete_max(h.right.take().unwrap());
+    Some(balance(h))
+}
+
+/// Removes the key below h, which has to be in the tree
+fn delete<T:Ord,K>(mut h: Box<Node<T,K>>, key: &T) -> Link<T,K> {
+    if *key < h.key {
+        if !is_red(&h.left) && !is_red(&h.left.as_ref().unwrap().left) {
+            h = move_red_left(h);
+        }
+        h.left = delete(h.left.take().unwrap(), key);
+    } else {
+        if is_red(&h.left) {
+            h = rotate_right(h);
+        }
+        if *key == h.key && h.right.is_none() {
+            return None;
+        }
+        if !is_red(&h.right) && !is_red(&h.right.as_ref().unwrap().left) {
+            h = move_red_right(h);
+        }
+        if *key == h.key {
+            // the node takes the place of its successor, which is removed from the right subtree
+            let (right, min) = delete_min(h.right.take().unwrap());
+            h.right = right;
+            h.key = min.key;
+            h.val = min.val;
+        } else {
+            h.right = delete(h.right.take().unwrap(), key);
+        }
+    }
+    Some(balance(h))
+}
+
+/// Rotates a red right link to the left, where the new root takes the color of h and h becomes red
+fn rotate_left<T,K>(h: Box<Node<T,K>>) -> Box<Node<T,K>> {
+    let color = h.color;
+    let mut x = search_tree::rotate_left(h);
+    x.color = color;
+    x.left.as_mut().unwrap().color = RED;
+    x
+}
+
+/// Rotates a red left link to the right, where the new root takes the color of h and h becomes red
+fn rotate_right<T,K>(h: Box<Node<T,K>>) -> Box<Node<T,K>> {
+    let color = h.color;
+    let mut x = search_tree::rotate_right(h);
+    x.color = color;
+    x.right.as_mut().unwrap().color = RED;
+    x
+}
+
+fn flip_colors<T,K>(h: &mut Node<T,K>) {
+    h.color = !h.color;
+    for child in [h.left.as_mut(), h.right.as_mut()].into_iter().flatten() {
+        child.color = !child.color;
+    }
+}
+
+/// Makes h.left or one of its children red, when h is red and both h.left and h.left.left are black
+fn move_red_left<T,K>(mut h: Box<Node<T,K>>) -> Box<Node<T,K>> {
+    flip_colors(&mut h);
+    if is_red(&h.right.as_ref().unwrap().left) {
+        h.right = Some(rotate_right(h.right.take().unwrap()));
+        h = rotate_left(h);
+        flip_colors(&mut h);
+    }
+    h
+}
+
+/// Makes h.right or one of its children red, when h is red and both h.right and h.right.left are black
+fn move_red_right<T,K>(mut h: Box<Node<T,K>>) -> Box<Node<T,K>> {
+    flip_colors(&mut h);
+    if is_red(&h.left.as_ref().unwrap().left) {
+        h = rotate_right(h);
+        flip_colors(&mut h);
+    }
+    h
+}
+
+/// Restores the invariants on the way up from an insertion or a deletion
+fn balance<T,K>(mut h: Box<Node<T,K>>) -> Box<Node<T,K>> {
+    if is_red(&h.right) && !is_red(&h.left) {
+        h = rotate_left(h);
+    }
+    if is_red(&h.left) && is_red(&h.left.as_ref().unwrap().left) {
+        h = rotate_right(h);
+    }
+    if is_red(&h.left) && is_red(&h.right) {
+        flip_colors(&mut h);
+    }
+    h.update();
+    h
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::randomization::lcg_random::lcg_generate_seeded;
+    use crate::searching::search_tree::tests::{check_empty, check_random_operations};
+
+    use super::{is_red, size, Link, RedBlackBST, BLACK};
+
+    /// Checks the symmetric order, the sizes and the 2-3 tree invariants, and returns the black height
+    fn check<T:Ord,K>(link: &Link<T,K>, lo: Option<&T>, hi: Option<&T>) -> usize {
+        let node = match link {
+            None => return 0,
+            Some(node) => node,
+        };
+        assert!(lo.is_none_or(|lo| *lo < node.key));
+        assert!(hi.is_none_or(|hi| node.key < *hi));
+        assert_eq!(node.size, 1 + size(&node.left) + size(&node.right));
+        // red links lean left and no node touches two red links
+        assert!(!is_red(&node.right));
+        assert!(!(is_red(link) && is_red(&node.left)));
+
+        let left = check(&node.left, lo, Some(&node.key));
+        let right = check(&node.right, Some(&node.key), hi);
+        // perfect black balance
+        assert_eq!(left, right);
+        left + if node.color == BLACK { 1 } else { 0 }
+    }
+
+    fn check_tree<T:Ord,K>(rb: &RedBlackBST<T,K>) {
+        assert!(!is_red(&rb.root));
+        check(&rb.root, None, None);
+        let n = rb.size() as f64;
+        assert!(rb.height() as f64 <= 2.0 * (n + 1.0).log2());
+    }
+
+    #[test]
+    fn test_sorted_inserts(){
+        let mut rb: RedBlackBST<u32,u32> = RedBlackBST::new();
+        for i in 0..1023 {
+            rb.put(i, i * 2);
+            check_tree(&rb);
+        }
+        assert_eq!(rb.size(), 1023);
+        assert_eq!(rb.get(&500), Some(&1000));
+        assert!(rb.height() <= 18);
+    }
+
+    #[test]
+    fn test_random_operations(){
+        check_random_operations(RedBlackBST::new(), 40, check_tree);
+    }
+
+    #[test]
+    fn test_ordered_operations(){
+        let rand = lcg_generate_seeded(1000, 45);
+        let mut rb: RedBlackBST<u32,()> = RedBlackBST::new();
+        let mut expected: BTreeMap<u32,()> = BTreeMap::new();
+        for r in rand {
+            rb.put(r % 2000, ());
+            expected.insert(r % 2000, ());
+        }
+        assert_eq!(rb.min(), expected.keys().next());
+        assert_eq!(rb.max(), expected.keys().next_back());
+        for key in 0..2010 {
+            assert_eq!(rb.floor(&key), expected.range(..=key).next_back().map(|(k, _)| k));
+            assert_eq!(rb.ceiling(&key), expected.range(key..).next().map(|(k, _)| k));
+            assert_eq!(rb.rank(&key), expected.range(..key).count());
+        }
+        for (r, key) in expected.keys().enumerate() {
+            assert_eq!(rb.select(r), Some(key));
+        }
+        assert_eq!(rb.select(expected.len()), None);
+
+        let mut keys = rb.keys();
+        for key in expected.keys() {
+            assert_eq!(keys.dequeue(), Some(key));
+        }
+        let mut keys = rb.keys_between(&500, &1500);
+        for key in expected.range(500..=1500).map(|(k, _)| k) {
+            assert_eq!(keys.dequeue(), Some(key));
+        }
+        assert!(keys.is_empty());
+        assert_eq!(rb.size_between(&500, &1500), expected.range(500..=1500).count());
+        assert_eq!(rb.size_between(&1500, &500), 0);
+    }
+
+    #[test]
+    fn test_empty(){
+        let rb = RedBlackBST::new();
+        assert_eq!(rb.height(), -1);
+        check_empty(rb);
+    }
+}
@@ -0,0 +1,276 @@
+use std::cmp::Ordering::*;
+
+use crate::fundamentals::queue::Queue;
+
+/// The TreeNode trait is the node layout shared by the binary search trees of the searching module,
+/// where every node has a key, a value, two children and the size of its subtree.
+/// The trees only differ in how they keep their balance, so the operations which only read the tree,
+/// and the rotations, are written once in this module over the trait.
+///
+/// Every read-only operation walks a single path from the root, so it takes time proportional to the height of the tree,
+/// except for `keys` and `keys_between`, which also take time proportional to the amount of keys they return.
+pub(crate) trait TreeNode: Sized {
+    type Key;
+    type Val;
+
+    fn key(&self) -> &Self::Key;
+
+    fn val(&self) -> &Self::Val;
+
+    fn left(&self) -> &Option<Box<Self>>;
+
+    fn right(&self) -> &Option<Box<Self>>;
+
+    fn left_mut(&mut self) -> &mut Option<Box<Self>>;
+
+    fn right_mut(&mut self) -> &mut Option<Box<Self>>;
+
+    /// The amount of nodes in the subtree rooted in the node
+    fn size(&self) -> usize;
+
+    /// Recomputes the size, and whatever else the tree keeps in its nodes, from the children
+    fn update(&mut self);
+}
+
+pub(crate) fn size<N: TreeNode>(link: &Option<Box<N>>) -> usize {
+    link.as_ref().map_or(0, |node| node.size())
+}
+
+/// The height of the tree, where a tree with a single node has height 0 and the empty tree has height -1.
+/// It visits every node
+pub(crate) fn height<N: TreeNode>(link: &Option<Box<N>>) -> isize {
+    link.as_ref().map_or(-1, |node| 1 + height(node.left()).max(height(node.right())))
+}
+
+pub(crate) fn get<'a,N: TreeNode>(link: &'a Option<Box<N>>, key: &N::Key) -> Option<&'a N::Val>
+    where N::Key: Ord
+{
+    let mut curr = link;
+    while let Some(node) = curr {
+        match key.cmp(node.key()) {
+            Less => curr = node.left(),
+            Greater => curr = node.right(),
+            Equal => return Some(node.val()),
+        }
+    }
+    None
+}
+
+/// The smallest key
+pub(crate) fn min<N: TreeNode>(link: &Option<Box<N>>) -> Option<&N::Key> {
+    let mut curr = link.as_ref()?;
+    while let Some(node) = curr.left() {
+        curr = node;
+    }
+    Some(curr.key())
+}
+
+/// The largest key
+pub(crate) fn max<N: TreeNode>(link: &Option<Box<N>>) -> Option<&N::Key> {
+    let mut curr = link.as_ref()?;
+    while let Some(node) = curr.right() {
+        curr = node;
+    }
+    Some(curr.key())
+}
+
+/// The largest key smaller than or equal to key
+pub(crate) fn floor<'a,N: TreeNode>(link: &'a Option<Box<N>>, key: &N::Key) -> Option<&'a N::Key>
+    where N::Key: Ord
+{
+    let mut curr = link;
+    let mut best = None;
+    while let Some(node) = curr {
+        match key.cmp(node.key()) {
+            Less => curr = node.left(),
+            Greater => {
+                best = Some(node.key());
+                curr = node.right();
+            }
+            Equal => return Some(node.key()),
+        }
+    }
+    best
+}
+
+/// The smallest key larger than or equal to key
+pub(crate) fn ceiling<'a,N: TreeNode>(link: &'a Option<Box<N>>, key: &N::Key) -> Option<&'a N::Key>
+    where N::Key: Ord
+{
+    let mut curr = link;
+    let mut best = None;
+    while let Some(node) = curr {
+        match key.cmp(node.key()) {
+            Less => {
+                best = Some(node.key());
+                curr = node.left();
+            }
+            Greater => curr = node.right(),
+            Equal => return Some(node.key()),
+        }
+    }
+    best
+}
+
+/// The amount of keys strictly smaller than key
+pub(crate) fn rank<N: TreeNode>(link: &Option<Box<N>>, key: &N::Key) -> usize
+    where N::Key: Ord
+{
+    let mut curr = link;
+    let mut rank = 0;
+    while let Some(node) = curr {
+        match key.cmp(node.key()) {
+            Less => curr = node.left(),
+            Greater => {
+                rank += 1 + size(node.left());
+                curr = node.right();
+            }
+            Equal => return rank + size(node.left()),
+        }
+    }
+    rank
+}
+
+/// The key of rank r, which is the r+1'th smallest key
+pub(crate) fn select<N: TreeNode>(link: &Option<Box<N>>, mut r: usize) -> Option<&N::Key> {
+    let mut curr = link;
+    while let Some(node) = curr {
+        let left = size(node.left());
+        match r.cmp(&left) {
+            Less => curr = node.left(),
+            Greater => {
+                r -= left + 1;
+                curr = node.right();
+            }
+            Equal => return Some(node.key()),
+        }
+    }
+    None
+}
+
+/// All the keys, in order
+pub(crate) fn keys<N: TreeNode>(link: &Option<Box<N>>) -> Queue<&N::Key>
+    where N::Key: Ord
+{
+    let mut queue = Queue::new();
+    if let (Some(lo), Some(hi)) = (min(link), max(link)) {
+        enqueue_between(link, lo, hi, &mut queue);
+    }
+    queue
+}
+
+/// The keys between lo and hi, both inclusive, in order
+pub(crate) fn keys_between<'a,N: TreeNode>(link: &'a Option<Box<N>>, lo: &N::Key, hi: &N::Key) -> Queue<&'a N::Key>
+    where N::Key: Ord
+{
+    let mut queue = Queue::new();
+    enqueue_between(link, lo, hi, &mut queue);
+    queue
+}
+
+/// The amount of keys between lo and hi, both inclusive
+pub(crate) fn size_between<N: TreeNode>(link: &Option<Box<N>>, lo: &N::Key, hi: &N::Key) -> usize
+    where N::Key: Ord
+{
+    if lo > hi {
+        return 0;
+    }
+    let below_hi = rank(link, hi) + if get(link, hi).is_some() { 1 } else { 0 };
+    below_hi - rank(link, lo)
+}
+
+/// Adds the keys between lo and hi (both inclusive) below link to the queue, in order
+fn enqueue_between<'a,N: TreeNode>(link: &'a Option<Box<N>>, lo: &N::Key, hi: &N::Key, queue: &mut Queue<&'a N::Key>)
+    where N::Key: Ord
+{
+    if let Some(node) = link {
+        let key = node.key();
+        if lo < key {
+            enqueue_between(node.left(), lo, hi, queue);
+        }
+        if lo <= key && key <= hi {
+            queue.enqueue(key);
+        }
+        if key < hi {
+            enqueue_between(node.right(), lo, hi, queue);
+        }
+    }
+}
+
+/// Makes the right child of h the root of the subtree, with h as its left child
+pub(crate) fn rotate_left<N: TreeNode>(mut h: Box<N>) -> Box<N> {
+    let mut x = h.right_mut().take().unwrap();
+    *h.right_mut() = x.left_mut().take();
+    h.update();
+    *x.left_mut() = Some(h);
+    x.update();
+    x
+}
+
+/// Makes the left child of h the root of the subtree, with h as its right child
+pub(crate) fn rotate_right<N: TreeNode>(mut h: Box<N>) -> Box<N> {
+    let mut x = h.left_mut().take().unwrap();
+    *h.left_mut() = x.right_mut().take();
+    h.update();
+    *x.right_mut() = Some(h);
+    x.update();
+    x
+}
+
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::randomization::lcg_random::lcg_generate_seeded;
+    use crate::searching::symbol_table::OrderedSymbolTable;
+
+    /// Runs a seeded mix of puts, deletes, delete_min and delete_max on the table and on a BTreeMap,
+    /// and checks the invariants of the tree with check after every operation
+    pub(crate) fn check_random_operations<ST: OrderedSymbolTable<u32,usize>>(mut st: ST, seed: u64, check: impl Fn(&ST)) {
+        let rand = lcg_generate_seeded(6000, seed);
+        let mut expected: BTreeMap<u32,usize> = BTreeMap::new();
+        for (i, c) in rand.chunks(2).enumerate() {
+            let key = c[1] % 200;
+            match c[0] % 5 {
+                0 | 1 => {
+                    st.put(key, i);
+                    expected.insert(key, i);
+                }
+                2 => {
+                    st.delete(&key);
+                    expected.remove(&key);
+                }
+                3 => {
+                    st.delete_min();
+                    expected.pop_first();
+                }
+                _ => {
+                    st.delete_max();
+                    expected.pop_last();
+                }
+            }
+            check(&st);
+            assert_eq!(st.len(), expected.len());
+            assert_eq!(st.get(&key), expected.get(&key));
+        }
+        for key in 0..200 {
+            assert_eq!(st.get(&key), expected.get(&key));
+        }
+    }
+
+    /// Checks that the deletions do nothing on an empty table, and that the table empties again
+    pub(crate) fn check_empty<ST: OrderedSymbolTable<i32,&'static str>>(mut st: ST) {
+        assert!(st.is_empty());
+        st.delete_min();
+        st.delete_max();
+        st.delete(&3);
+        st.put(3, "three");
+        st.delete(&4);
+        assert_eq!(st.len(), 1);
+        st.delete(&3);
+        assert!(st.is_empty());
+        assert_eq!(st.min(), None);
+        assert_eq!(st.select(0), None);
+    }
+}
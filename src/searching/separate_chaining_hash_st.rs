@@ -0,0 +1,213 @@
+use std::{collections::hash_map::RandomState, hash::{BuildHasher, Hash}};
+
+use crate::fundamentals::queue::Queue;
+
+const INIT_CAPACITY: usize = 4;
+// the largest average amount of keys in a chain before the table grows
+const MAX_AVERAGE_CHAIN: usize = 10;
+
+/// The SeparateChainingHashST class represents an unordered symbol table of generic key value pairs,
+/// stored in a hash table where every bucket is a chain of the pairs whose key hashes to it.
+/// It supports the operations `put`, `get`, `contains`, `delete`, `size` and `keys`.
+///
+/// The amount of chains is doubled when the average chain holds 10 keys, and halved when it holds 2 or fewer,
+/// so with a hash function that spreads the keys evenly every operation takes constant amortized time.
+/// The hash function comes from a `BuildHasher`, which is `RandomState` unless another one is given with `with_hasher`.
+///
+/// For additional documentation, see Section 3.4 of Algorithms, 4th Edition by Robert Sedgewick and Kevin Wayne.
+///
+/// # Examples
+///
+/// ```
+/// use itualgs_rs::searching::separate_chaining_hash_st::SeparateChainingHashST;
+///
+/// let mut st: SeparateChainingHashST<&str,u32> = SeparateChainingHashST::new();
+/// st.put("Ferris", 1);
+/// st.put("John", 2);
+/// st.put("Ferris", 3);
+///
+/// assert_eq!(st.get(&"Ferris"), Some(&3));
+/// assert_eq!(st.size(), 2);
+///
+/// st.delete(&"John");
+/// assert!(!st.contains(&"John"));
+/// ```
+pub struct SeparateChainingHashST<T,K,S = RandomState> {
+    n: usize,
+    chains: Vec<Vec<(T,K)>>,
+    hasher: S,
+}
+
+impl<T,K> SeparateChainingHashST<T,K>
+    where T:Hash + Eq
+{
+    pub fn new() -> Self {
+        Self::with_hasher(RandomState::new())
+    }
+}
+
+impl<T,K> Default for SeparateChainingHashST<T,K>
+    where T:Hash + Eq
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T,K,S> SeparateChainingHashST<T,K,S>
+    where T:Hash + Eq, S:BuildHasher
+{
+    /// Creates an empty table, which hashes the keys with the given hasher
+    pub fn with_hasher(hasher: S) -> Self {
+        Self::with_capacity_and_hasher(INIT_CAPACITY, hasher)
+    }
+
+    /// Creates an empty table with m chains, which hashes the keys with the given hasher
+    pub fn with_capacity_and_hasher(m: usize, hasher: S) -> Self {
+        let chains = (0..m.max(INIT_CAPACITY)).map(|_| Vec::new()).collect();
+        SeparateChainingHashST { n: 0, chains, hasher }
+    }
+
+    /// The amount of key value pairs
+    pub fn size(&self) -> usize {
+        self.n
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+
+    /// The amount of chains
+    pub fn capacity(&self) -> usize {
+        self.chains.len()
+    }
+
+    pub fn get(&self, key: &T) -> Option<&K> {
+        self.chains[self.hash(key)].iter().find(|(k, _)| k == key).map(|(_, v)| v)
+    }
+
+    pub fn contains(&self, key: &T) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Inserts the key value pair, overwriting the value if the key is already in the table
+    pub fn put(&mut self, key: T, val: K) {
+        let i = self.hash(&key);
+        if let Some(pair) = self.chains[i].iter_mut().find(|(k, _)| *k == key) {
+            pair.1 = val;
+            return;
+        }
+        if self.n >= MAX_AVERAGE_CHAIN * self.chains.len() {
+            self.resize(2 * self.chains.len());
+        }
+        let i = self.hash(&key);
+        self.chains[i].push((key, val));
+        self.n += 1;
+    }
+
+    /// Removes the key and its value, if the key is in the table
+    pub fn delete(&mut self, key: &T) {
+        let i = self.hash(key);
+        if let Some(j) = self.chains[i].iter().position(|(k, _)| k == key) {
+            self.chains[i].swap_remove(j);
+            self.n -= 1;
+        }
+        if self.chains.len() > INIT_CAPACITY && self.n <= 2 * self.chains.len() {
+            self.resize(self.chains.len() / 2);
+        }
+    }
+
+    /// All the keys, in no particular order
+    pub fn keys(&self) -> Queue<&T> {
+        let mut queue = Queue::new();
+        for (key, _) in self.chains.iter().flatten() {
+            queue.enqueue(key);
+        }
+        queue
+    }
+
+    fn hash(&self, key: &T) -> usize {
+        (self.hasher.hash_one(key) % self.chains.len() as u64) as usize
+    }
+
+    /// Moves every pair to a table of m chains
+    fn resize(&mut self, m: usize) {
+        let old = std::mem::replace(&mut self.chains, (0..m).map(|_| Vec::new()).collect());
+        for (key, val) in old.into_iter().flatten() {
+            let i = self.hash(&key);
+            self.chains[i].push((key, val));
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::hash::{BuildHasherDefault, Hasher};
+
+    use crate::randomization::lcg_random::lcg_generate_seeded;
+
+    use super::{SeparateChainingHashST, INIT_CAPACITY, MAX_AVERAGE_CHAIN};
+
+    /// A hasher that sends every key to the same chain
+    #[derive(Default)]
+    struct ConstantHasher;
+
+    impl Hasher for ConstantHasher {
+        fn finish(&self) -> u64 {
+            0
+        }
+
+        fn write(&mut self, _: &[u8]) {}
+    }
+
+    fn check_load<S: std::hash::BuildHasher>(st: &SeparateChainingHashST<u32,usize,S>) {
+        let m = st.capacity();
+        assert!(m >= INIT_CAPACITY);
+        assert!(st.size() <= MAX_AVERAGE_CHAIN * m);
+        // the table shrinks as soon as the average chain holds 2 keys
+        assert!(m == INIT_CAPACITY || st.size() >= 2 * m);
+        assert_eq!(st.chains.iter().map(|c| c.len()).sum::<usize>(), st.size());
+    }
+
+    #[test]
+    fn test_same_as_hashmap() {
+        let rand = lcg_generate_seeded(8000, 44);
+        let mut st: SeparateChainingHashST<u32,usize> = SeparateChainingHashST::new();
+        let mut expected: HashMap<u32,usize> = HashMap::new();
+        for (i, c) in rand.chunks(2).enumerate() {
+            let key = c[1] % 1000;
+            // mostly puts at first, and mostly deletes at the end, so the table both grows and shrinks
+            if (c[0] as usize % 4000) < 4000 - i {
+                st.put(key, i);
+                expected.insert(key, i);
+            } else {
+                st.delete(&key);
+                expected.remove(&key);
+            }
+            check_load(&st);
+            assert_eq!(st.size(), expected.len());
+            assert_eq!(st.get(&key), expected.get(&key));
+        }
+        assert_eq!(st.keys().size(), expected.len());
+        for key in 0..1000 {
+            assert_eq!(st.get(&key), expected.get(&key));
+        }
+    }
+
+    #[test]
+    fn test_colliding_hasher() {
+        let mut st: SeparateChainingHashST<u32,usize,_> = SeparateChainingHashST::with_hasher(BuildHasherDefault::<ConstantHasher>::default());
+        for key in 0..100 {
+            st.put(key, key as usize);
+        }
+        for key in (0..100).step_by(2) {
+            st.delete(&key);
+        }
+        check_load(&st);
+        for key in 0..100 {
+            assert_eq!(st.get(&key).copied(), if key % 2 == 1 { Some(key as usize) } else { None });
+        }
+    }
+}
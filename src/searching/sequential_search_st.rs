@@ -0,0 +1,187 @@
+use crate::fundamentals::queue::Queue;
+
+/// The SequentialSearchST class represents an unordered symbol table of generic key value pairs,
+/// stored in a linked list. A search walks the list from the front and compares the keys for equality.
+/// It supports the operations `put`, `get`, `contains`, `delete`, `size` and `keys`.
+///
+/// Every operation except `size` takes *O(N)* time, since the key has to be compared with every key in the list.
+/// It only needs the keys to be comparable for equality, and it is the baseline the other symbol tables are compared to.
+///
+/// For additional documentation, see Section 3.1 of Algorithms, 4th Edition by Robert Sedgewick and Kevin Wayne.
+///
+/// # Examples
+///
+/// ```
+/// use itualgs_rs::searching::sequential_search_st::SequentialSearchST;
+///
+/// let mut st: SequentialSearchST<&str,u32> = SequentialSearchST::new();
+/// st.put("Ferris", 1);
+/// st.put("John", 2);
+/// st.put("Ferris", 3);
+///
+/// assert_eq!(st.get(&"Ferris"), Some(&3));
+/// assert_eq!(st.size(), 2);
+///
+/// st.delete(&"John");
+/// assert!(!st.contains(&"John"));
+/// ```
+pub struct SequentialSearchST<T,K> {
+    n: usize,
+    first: Link<T,K>,
+}
+
+type Link<T,K> = Option<Box<Node<T,K>>>;
+
+struct Node<T,K> {
+    key: T,
+    val: K,
+    next: Link<T,K>,
+}
+
+impl<T,K> Default for SequentialSearchST<T,K>
+    where T:PartialEq
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T,K> SequentialSearchST<T,K>
+    where T:PartialEq
+{
+    pub fn new() -> Self {
+        SequentialSearchST { n: 0, first: None }
+    }
+
+    /// The amount of key value pairs
+    pub fn size(&self) -> usize {
+        self.n
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+
+    pub fn get(&self, key: &T) -> Option<&K> {
+        let mut curr = &self.first;
+        while let Some(node) = curr {
+            if node.key == *key {
+                return Some(&node.val);
+            }
+            curr = &node.next;
+        }
+        None
+    }
+
+    pub fn contains(&self, key: &T) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Inserts the key value pair, overwriting the value if the key is already in the table.
+    /// A new key is added to the front of the list
+    pub fn put(&mut self, key: T, val: K) {
+        let mut curr = &mut self.first;
+        while let Some(node) = curr {
+            if node.key == key {
+                node.val = val;
+                return;
+            }
+            curr = &mut node.next;
+        }
+        let next = self.first.take();
+        self.first = Some(Box::new(Node { key, val, next }));
+        self.n += 1;
+    }
+
+    /// Removes the key and its value, if the key is in the table
+    pub fn delete(&mut self, key: &T) {
+        // the link pointing to the node with the key is made to point past it
+        let mut curr = &mut self.first;
+        while curr.as_ref().is_some_and(|node| node.key != *key) {
+            curr = &mut curr.as_mut().unwrap().next;
+        }
+        if let Some(node) = curr.take() {
+            *curr = node.next;
+            self.n -= 1;
+        }
+    }
+
+    /// All the keys, from the most recently added
+    pub fn keys(&self) -> Queue<&T> {
+        let mut queue = Queue::new();
+        let mut curr = &self.first;
+        while let Some(node) = curr {
+            queue.enqueue(&node.key);
+            curr = &node.next;
+        }
+        queue
+    }
+}
+
+impl<T,K> Drop for SequentialSearchST<T,K> {
+    /// The nodes are dropped one at a time, since dropping the first node would otherwise drop the whole list recursively
+    fn drop(&mut self) {
+        let mut curr = self.first.take();
+        while let Some(mut node) = curr {
+            curr = node.next.take();
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::randomization::lcg_random::lcg_generate_seeded;
+
+    use super::SequentialSearchST;
+
+    #[test]
+    fn test_same_as_hashmap() {
+        let rand = lcg_generate_seeded(3000, 46);
+        let mut st: SequentialSearchST<u32,usize> = SequentialSearchST::new();
+        let mut expected: HashMap<u32,usize> = HashMap::new();
+        for (i, c) in rand.chunks(2).enumerate() {
+            let key = c[1] % 100;
+            if c[0] % 3 == 0 {
+                st.delete(&key);
+                expected.remove(&key);
+            } else {
+                st.put(key, i);
+                expected.insert(key, i);
+            }
+            assert_eq!(st.size(), expected.len());
+            assert_eq!(st.get(&key), expected.get(&key));
+        }
+        assert_eq!(st.keys().size(), expected.len());
+    }
+
+    #[test]
+    fn test_delete_ends() {
+        let mut st: SequentialSearchST<u8,()> = SequentialSearchST::new();
+        for key in 0..4 {
+            st.put(key, ());
+        }
+        // the list is 3 2 1 0
+        st.delete(&3);
+        st.delete(&0);
+        st.delete(&7);
+        let mut keys = st.keys();
+        assert_eq!(keys.dequeue(), Some(&2));
+        assert_eq!(keys.dequeue(), Some(&1));
+        assert!(keys.is_empty());
+        assert_eq!(st.size(), 2);
+    }
+
+    #[test]
+    fn test_long_list() {
+        let mut st: SequentialSearchST<u32,()> = SequentialSearchST::new();
+        // put is linear, so a long list is built from the front without the search
+        for key in 0..200_000 {
+            let next = st.first.take();
+            st.first = Some(Box::new(super::Node { key, val: (), next }));
+        }
+        drop(st);
+    }
+}
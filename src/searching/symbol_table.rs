@@ -0,0 +1,427 @@
+use std::hash::{BuildHasher, Hash};
+
+use crate::fundamentals::queue::Queue;
+
+use super::{
+    BST::BST,
+    avl_tree::AVLTreeST,
+    b_tree::BTree,
+    binary_search_st::BinarySearchST,
+    interval_st::{Interval, IntervalST},
+    linear_probing_hash_st::LinearProbingHashST,
+    red_black_bst::RedBlackBST,
+    separate_chaining_hash_st::SeparateChainingHashST,
+    sequential_search_st::SequentialSearchST,
+    treap::Treap,
+};
+
+/// The SymbolTable trait is the API shared by all the symbol tables of the searching module,
+/// so a client like a frequency counter can be written once and run with any of them.
+/// A symbol table maps every key to one value, and putting a key that is already in the table replaces its value.
+///
+/// # Examples
+/// ```
+/// use itualgs_rs::searching::symbol_table::SymbolTable;
+/// use itualgs_rs::searching::BST::BST;
+/// use itualgs_rs::searching::linear_probing_hash_st::LinearProbingHashST;
+///
+/// fn count<ST: SymbolTable<char, usize>>(mut st: ST, text: &str) -> ST {
+///     for c in text.chars() {
+///         let seen = st.get(&c).copied().unwrap_or(0);
+///         st.put(c, seen + 1);
+///     }
+///     st
+/// }
+///
+/// assert_eq!(count(BST::new(), "banana").get(&'a'), Some(&3));
+/// assert_eq!(count(LinearProbingHashST::new(), "banana").len(), 3);
+/// ```
+pub trait SymbolTable<T, K> {
+    /// Inserts the key value pair, overwriting the value if the key is already in the table
+    fn put(&mut self, key: T, val: K);
+
+    /// The value of the key
+    fn get(&self, key: &T) -> Option<&K>;
+
+    /// Removes the key and its value, if the key is in the table
+    fn delete(&mut self, key: &T);
+
+    /// The amount of key value pairs
+    fn len(&self) -> usize;
+
+    /// All the keys, in order for an ordered table and in no particular order otherwise
+    fn keys(&self) -> Queue<&T>;
+
+    fn contains(&self, key: &T) -> bool {
+        self.get(key).is_some()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// The OrderedSymbolTable trait is the API of the symbol tables which keep their keys in order,
+/// adding the operations that use the order of the keys to the `SymbolTable` API.
+///
+/// # Examples
+/// ```
+/// use itualgs_rs::searching::symbol_table::{OrderedSymbolTable, SymbolTable};
+/// use itualgs_rs::searching::red_black_bst::RedBlackBST;
+///
+/// fn median<ST: OrderedSymbolTable<u32, ()>>(st: &ST) -> Option<&u32> {
+///     st.select(st.len() / 2)
+/// }
+///
+/// let mut rb = RedBlackBST::new();
+/// for key in [5, 1, 9, 3, 7] {
+///     SymbolTable::put(&mut rb, key, ());
+/// }
+/// assert_eq!(median(&rb), Some(&5));
+/// ```
+pub trait OrderedSymbolTable<T, K>: SymbolTable<T, K> {
+    /// The smallest key
+    fn min(&self) -> Option<&T>;
+
+    /// The largest key
+    fn max(&self) -> Option<&T>;
+
+    /// The largest key smaller than or equal to key
+    fn floor(&self, key: &T) -> Option<&T>;
+
+    /// The smallest key larger than or equal to key
+    fn ceiling(&self, key: &T) -> Option<&T>;
+
+    /// The amount of keys strictly smaller than key
+    fn rank(&self, key: &T) -> usize;
+
+    /// The key of rank r
+    fn select(&self, r: usize) -> Option<&T>;
+
+    /// Removes the smallest key and its value
+    fn delete_min(&mut self);
+
+    /// Removes the largest key and its value
+    fn delete_max(&mut self);
+
+    /// The keys between lo and hi, both inclusive, in order
+    fn keys_between(&self, lo: &T, hi: &T) -> Queue<&T>;
+
+    /// The amount of keys between lo and hi, both inclusive
+    fn size_between(&self, lo: &T, hi: &T) -> usize;
+}
+
+/// Implements `SymbolTable` for a table by calling the methods of the table with the same names
+macro_rules! symbol_table {
+    ($table:ident) => {
+        impl<T: Ord, K> SymbolTable<T, K> for $table<T, K> {
+            fn put(&mut self, key: T, val: K) {
+                $table::put(self, key, val)
+            }
+
+            fn get(&self, key: &T) -> Option<&K> {
+                $table::get(self, key)
+            }
+
+            fn delete(&mut self, key: &T) {
+                $table::delete(self, key)
+            }
+
+            fn len(&self) -> usize {
+                $table::size(self)
+            }
+
+            fn keys(&self) -> Queue<&T> {
+                $table::keys(self)
+            }
+
+            fn contains(&self, key: &T) -> bool {
+                $table::contains(self, key)
+            }
+        }
+    };
+}
+
+/// Implements `OrderedSymbolTable` for a table by calling the methods of the table with the same names
+macro_rules! ordered_symbol_table {
+    ($table:ident) => {
+        impl<T: Ord, K> OrderedSymbolTable<T, K> for $table<T, K> {
+            fn min(&self) -> Option<&T> {
+                $table::min(self)
+            }
+
+            fn max(&self) -> Option<&T> {
+                $table::max(self)
+            }
+
+            fn floor(&self, key: &T) -> Option<&T> {
+                $table::floor(self, key)
+            }
+
+            fn ceiling(&self, key: &T) -> Option<&T> {
+                $table::ceiling(self, key)
+            }
+
+            fn rank(&self, key: &T) -> usize {
+                $table::rank(self, key)
+            }
+
+            fn select(&self, r: usize) -> Option<&T> {
+                $table::select(self, r)
+            }
+
+            fn delete_min(&mut self) {
+                $table::delete_min(self)
+            }
+
+            fn delete_max(&mut self) {
+                $table::delete_max(self)
+            }
+
+            fn keys_between(&self, lo: &T, hi: &T) -> Queue<&T> {
+                $table::keys_between(self, lo, hi)
+            }
+
+            fn size_between(&self, lo: &T, hi: &T) -> usize {
+                $table::size_between(self, lo, hi)
+            }
+        }
+    };
+}
+
+symbol_table!(BST);
+ordered_symbol_table!(BST);
+symbol_table!(RedBlackBST);
+ordered_symbol_table!(RedBlackBST);
+symbol_table!(BinarySearchST);
+ordered_symbol_table!(BinarySearchST);
+symbol_table!(AVLTreeST);
+ordered_symbol_table!(AVLTreeST);
+symbol_table!(Treap);
+ordered_symbol_table!(Treap);
+symbol_table!(BTree);
+ordered_symbol_table!(BTree);
+
+/// A sequential search only compares keys for equality, so it does not need them to be ordered
+impl<T: PartialEq, K> SymbolTable<T, K> for SequentialSearchST<T, K> {
+    fn put(&mut self, key: T, val: K) {
+        SequentialSearchST::put(self, key, val)
+    }
+
+    fn get(&self, key: &T) -> Option<&K> {
+        SequentialSearchST::get(self, key)
+    }
+
+    fn delete(&mut self, key: &T) {
+        SequentialSearchST::delete(self, key)
+    }
+
+    fn len(&self) -> usize {
+        SequentialSearchST::size(self)
+    }
+
+    fn keys(&self) -> Queue<&T> {
+        SequentialSearchST::keys(self)
+    }
+
+    fn contains(&self, key: &T) -> bool {
+        SequentialSearchST::contains(self, key)
+    }
+}
+
+/// The keys of an interval table are intervals of the endpoint type, and the largest ends are cloned into the nodes
+impl<T: Ord + Clone, K> SymbolTable<Interval<T>, K> for IntervalST<T, K> {
+    fn put(&mut self, key: Interval<T>, val: K) {
+        IntervalST::put(self, key, val)
+    }
+
+    fn get(&self, key: &Interval<T>) -> Option<&K> {
+        IntervalST::get(self, key)
+    }
+
+    fn delete(&mut self, key: &Interval<T>) {
+        IntervalST::delete(self, key)
+    }
+
+    fn len(&self) -> usize {
+        IntervalST::size(self)
+    }
+
+    fn keys(&self) -> Queue<&Interval<T>> {
+        IntervalST::keys(self)
+    }
+
+    fn contains(&self, key: &Interval<T>) -> bool {
+        IntervalST::contains(self, key)
+    }
+}
+
+/// Implements `SymbolTable` for a hash table, which is generic over its hasher
+macro_rules! hash_symbol_table {
+    ($table:ident) => {
+        impl<T: Hash + Eq, K, S: BuildHasher> SymbolTable<T, K> for $table<T, K, S> {
+            fn put(&mut self, key: T, val: K) {
+                $table::put(self, key, val)
+            }
+
+            fn get(&self, key: &T) -> Option<&K> {
+                $table::get(self, key)
+            }
+
+            fn delete(&mut self, key: &T) {
+                $table::delete(self, key)
+            }
+
+            fn len(&self) -> usize {
+                $table::size(self)
+            }
+
+            fn keys(&self) -> Queue<&T> {
+                $table::keys(self)
+            }
+
+            fn contains(&self, key: &T) -> bool {
+                $table::contains(self, key)
+            }
+        }
+    };
+}
+
+hash_symbol_table!(SeparateChainingHashST);
+hash_symbol_table!(LinearProbingHashST);
+
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::randomization::lcg_random::lcg_generate_seeded;
+    use crate::searching::{
+        BST::BST,
+        avl_tree::AVLTreeST,
+        b_tree::BTree,
+        binary_search_st::BinarySearchST,
+        interval_st::{Interval, IntervalST},
+        linear_probing_hash_st::LinearProbingHashST,
+        red_black_bst::RedBlackBST,
+        separate_chaining_hash_st::SeparateChainingHashST,
+        sequential_search_st::SequentialSearchST,
+        treap::Treap,
+    };
+
+    use super::{OrderedSymbolTable, SymbolTable};
+
+    const TEXT: &str = "it was the best of times it was the worst of times \
+        it was the age of wisdom it was the age of foolishness";
+
+    /// Counts how many times every word of at least min_len letters occurs
+    fn frequency_counter<ST: SymbolTable<String, usize>>(mut st: ST, text: &str, min_len: usize) -> ST {
+        for word in text.split_whitespace().filter(|w| w.len() >= min_len) {
+            let count = st.get(&word.to_string()).copied().unwrap_or(0);
+            st.put(word.to_string(), count + 1);
+        }
+        st
+    }
+
+    /// The word that occurs the most, the smallest one on a tie
+    fn most_frequent<ST: SymbolTable<String, usize>>(st: &ST) -> Option<(String, usize)> {
+        let mut keys = st.keys();
+        let mut best: Option<(String, usize)> = None;
+        while let Some(key) = keys.dequeue() {
+            let count = *st.get(key).unwrap();
+            if best.as_ref().is_none_or(|(w, c)| count > *c || (count == *c && key < w)) {
+                best = Some((key.clone(), count));
+            }
+        }
+        best
+    }
+
+    fn check_counter<ST: SymbolTable<String, usize>>(st: ST) {
+        let mut st = frequency_counter(st, TEXT, 2);
+        assert_eq!(st.len(), 10);
+        assert_eq!(st.get(&"it".to_string()), Some(&4));
+        assert_eq!(st.get(&"age".to_string()), Some(&2));
+        assert!(!st.contains(&"a".to_string()));
+        assert_eq!(most_frequent(&st), Some(("it".to_string(), 4)));
+
+        st.delete(&"it".to_string());
+        st.delete(&"of".to_string());
+        assert_eq!(st.len(), 8);
+        assert_eq!(most_frequent(&st), Some(("the".to_string(), 4)));
+    }
+
+    #[test]
+    fn test_frequency_counter() {
+        check_counter(BST::new());
+        check_counter(RedBlackBST::new());
+        check_counter(SeparateChainingHashST::new());
+        check_counter(LinearProbingHashST::new());
+        check_counter(SequentialSearchST::new());
+        check_counter(BinarySearchST::new());
+        check_counter(AVLTreeST::new());
+        check_counter(Treap::with_seed(48));
+        check_counter(BTree::new());
+    }
+
+    fn check_ordered<ST: OrderedSymbolTable<u32, usize>>(mut st: ST) {
+        let rand = lcg_generate_seeded(2000, 45);
+        let mut expected = BTreeMap::new();
+        for (i, r) in rand.iter().enumerate() {
+            let key = r % 500;
+            if i % 7 == 6 {
+                st.delete(&key);
+                expected.remove(&key);
+            } else {
+                st.put(key, i);
+                expected.insert(key, i);
+            }
+        }
+        st.delete_min();
+        expected.pop_first();
+        st.delete_max();
+        expected.pop_last();
+
+        assert_eq!(st.len(), expected.len());
+        assert_eq!(st.min(), expected.keys().next());
+        assert_eq!(st.max(), expected.keys().next_back());
+        for key in 0..510 {
+            assert_eq!(st.floor(&key), expected.range(..=key).next_back().map(|(k, _)| k));
+            assert_eq!(st.ceiling(&key), expected.range(key..).next().map(|(k, _)| k));
+            assert_eq!(st.rank(&key), expected.range(..key).count());
+        }
+        for (r, key) in expected.keys().enumerate() {
+            assert_eq!(st.select(r), Some(key));
+        }
+        let mut keys = st.keys();
+        for key in expected.keys() {
+            assert_eq!(keys.dequeue(), Some(key));
+        }
+        assert_eq!(st.keys_between(&100, &200).size(), expected.range(100..=200).count());
+        assert_eq!(st.size_between(&100, &200), expected.range(100..=200).count());
+    }
+
+    #[test]
+    fn test_ordered_tables() {
+        check_ordered(BST::new());
+        check_ordered(RedBlackBST::new());
+        check_ordered(BinarySearchST::new());
+        check_ordered(AVLTreeST::new());
+        check_ordered(Treap::with_seed(48));
+        check_ordered(BTree::new());
+    }
+
+    #[test]
+    fn test_interval_table() {
+        fn fill<ST: SymbolTable<Interval<u32>, &'static str>>(mut st: ST) -> ST {
+            st.put(Interval::new(9, 10), "standup");
+            st.put(Interval::new(13, 15), "review");
+            st.put(Interval::new(9, 10), "planning");
+            st.delete(&Interval::new(1, 2));
+            st
+        }
+        let st = fill(IntervalST::with_seed(50));
+        assert_eq!(SymbolTable::len(&st), 2);
+        assert_eq!(SymbolTable::get(&st, &Interval::new(9, 10)), Some(&"planning"));
+        assert_eq!(SymbolTable::keys(&st).dequeue(), Some(&Interval::new(9, 10)));
+    }
+}
@@ -0,0 +1,345 @@
+use std::cmp::Ordering::*;
+
+use crate::fundamentals::queue::Queue;
+use crate::randomization::lcg_random::LcgRandom;
+
+use super::search_tree::{self, height, rotate_left, rotate_right, size, TreeNode};
+
+/// The Treap class represents an ordered symbol table of generic key value pairs,
+/// stored in a treap, which is a binary search tree on the keys and a heap on random priorities given to the nodes.
+/// It supports the operations `put`, `get`, `contains`, `delete`, `delete_max` and `delete_min`, together with `size` and `height`,
+/// and the ordered operations `min`, `max`, `floor`, `ceiling`, `rank`, `select`, `keys_between` and `size_between`.
+///
+/// The priorities are drawn from an `LcgRandom`, and every node has a larger priority than its children.
+/// A new node is inserted as a leaf and rotated up until its parent has a larger priority,
+/// and a deleted node is replaced by the join of its two subtrees.
+/// The tree has the same shape as a binary search tree built by inserting the keys in the order of their priorities,
+/// so whatever order the keys are inserted in, the expected height is *O(log N)* and every operation takes expected *O(log N)* time.
+///
+/// # Examples
+///
+/// ```
+/// use itualgs_rs::searching::treap::Treap;
+///
+/// let mut treap: Treap<u32,&str> = Treap::with_seed(42);
+/// for i in 0..1000 {
+///     treap.put(i, "val");
+/// }
+/// treap.put(4, "val4");
+///
+/// assert_eq!(treap.get(&4).unwrap(), &"val4");
+/// assert_eq!(treap.size(), 1000);
+/// assert!(treap.height() < 100);
+///
+/// treap.delete(&4);
+/// treap.delete_min();
+/// assert!(!treap.contains(&4));
+/// assert_eq!(treap.min(), Some(&1));
+/// assert_eq!(treap.floor(&4), Some(&3));
+/// assert_eq!(treap.rank(&10), 8);
+/// ```
+pub struct Treap<T,K> {
+    root: Link<T,K>,
+    random: LcgRandom,
+}
+
+type Link<T,K> = Option<Box<Node<T,K>>>;
+
+struct Node<T,K> {
+    key: T,
+    val: K,
+    left: Link<T,K>,
+    right: Link<T,K>,
+    priority: u32,
+    size: usize,
+}
+
+impl<T,K> TreeNode for Node<T,K> {
+    type Key = T;
+    type Val = K;
+
+    fn key(&self) -> &T {
+        &self.key
+    }
+
+    fn val(&self) -> &K {
+        &self.val
+    }
+
+    fn left(&self) -> &Link<T,K> {
+        &self.left
+    }
+
+    fn right(&self) -> &Link<T,K> {
+        &self.right
+    }
+
+    fn left_mut(&mut self) -> &mut Link<T,K> {
+        &mut self.left
+    }
+
+    fn right_mut(&mut self) -> &mut Link<T,K> {
+        &mut self.right
+    }
+
+    fn size(&self) -> usize {
+        self.size
+    }
+
+    fn update(&mut self) {
+        self.size = 1 + size(&self.left) + size(&self.right);
+    }
+}
+
+impl<T,K> Default for Treap<T,K>
+    where T:Ord
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T,K> Treap<T,K>
+    where T:Ord
+{
+    /// Creates an empty treap, whose priorities are seeded with the current system time
+    pub fn new() -> Self {
+        Treap { root: None, random: LcgRandom::new() }
+    }
+
+    /// Creates an empty treap, whose priorities are seeded with the given seed, so the shape of the tree can be reproduced
+    pub fn with_seed(seed: u64) -> Self {
+        Treap { root: None, random: LcgRandom::with_seed(seed) }
+    }
+
+    /// The amount of key value pairs
+    pub fn size(&self) -> usize {
+        size(&self.root)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    /// The height of the tree, where a tree with a single node has height 0 and the empty tree has height -1
+    pub fn height(&self) -> isize {
+        height(&self.root)
+    }
+
+    pub fn get(&self, key: &T) -> Option<&K> {
+        search_tree::get(&self.root, key)
+    }
+
+    pub fn contains(&self, key: &T) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// The smallest key
+    pub fn min(&self) -> Option<&T> {
+        search_tree::min(&self.root)
+    }
+
+    /// The largest key
+    pub fn max(&self) -> Option<&T> {
+        search_tree::max(&self.root)
+    }
+
+    /// The largest key smaller than or equal to key
+    pub fn floor(&self, key: &T) -> Option<&T> {
+        search_tree::floor(&self.root, key)
+    }
+
+    /// The smallest key larger than or equal to key
+    pub fn ceiling(&self, key: &T) -> Option<&T> {
+        search_tree::ceiling(&self.root, key)
+    }
+
+    /// The amount of keys strictly smaller than key
+    pub fn rank(&self, key: &T) -> usize {
+        search_tree::rank(&self.root, key)
+    }
+
+    /// The key of rank r, which is the r+1'th smallest key
+    pub fn select(&self, r: usize) -> Option<&T> {
+        search_tree::select(&self.root, r)
+    }
+
+    /// All the keys, in order
+    pub fn keys(&self) -> Queue<&T> {
+        search_tree::keys(&self.root)
+    }
+
+    /// The keys between lo and hi, both inclusive, in order
+    pub fn keys_between(&self, lo: &T, hi: &T) -> Queue<&T> {
+        search_tree::keys_between(&self.root, lo, hi)
+    }
+
+    /// The amount of keys between lo and hi, both inclusive
+    pub fn size_between(&self, lo: &T, hi: &T) -> usize {
+        search_tree::size_between(&self.root, lo, hi)
+    }
+
+    /// Inserts the key value pair, overwriting the value if the key is already in the table
+    pub fn put(&mut self, key: T, val: K) {
+        let priority = self.random.next_u32();
+        self.root = Some(put(self.root.take(), key, val, priority));
+    }
+
+    pub fn delete_min(&mut self) {
+        if let Some(root) = self.root.take() {
+            self.root = delete_min(root);
+        }
+    }
+
+    pub fn delete_max(&mut self) {
+        if let Some(root) = self.root.take() {
+            self.root = delete_max(root);
+        }
+    }
+
+    /// Removes the key and its value, if the key is in the table
+    pub fn delete(&mut self, key: &T) {
+        self.root = delete(self.root.take(), key);
+    }
+}
+
+fn priority_of<T,K>(link: &Link<T,K>) -> Option<u32> {
+    link.as_ref().map(|node| node.priority)
+}
+
+/// Inserts the key as a leaf, and rotates it up past the nodes with a smaller priority
+fn put<T:Ord,K>(link: Link<T,K>, key: T, val: K, priority: u32) -> Box<Node<T,K>> {
+    let mut h = match link {
+        None => return Box::new(Node { key, val, left: None, right: None, priority, size: 1 }),
+        Some(h) => h,
+    };
+    match key.cmp(&h.key) {
+        Less => {
+            h.left = Some(put(h.left.take(), key, val, priority));
+            if priority_of(&h.left) > Some(h.priority) {
+                h = rotate_right(h);
+            }
+        }
+        Greater => {
+            h.right = Some(put(h.right.take(), key, val, priority));
+            if priority_of(&h.right) > Some(h.priority) {
+                h = rotate_left(h);
+            }
+        }
+        Equal => h.val = val,
+    }
+    h.update();
+    h
+}
+
+/// Removes the smallest node below h, whose right subtree takes its place
+fn delete_min<T,K>(mut h: Box<Node<T,K>>) -> Link<T,K> {
+    match h.left.take() {
+        None => h.right.take(),
+        Some(left) => {
+            h.left = delete_min(left);
+            h.update();
+            Some(h)
+        }
+    }
+}
+
+/// Removes the largest node below h, whose left subtree takes its place
+fn delete_max<T,K>(mut h: Box<Node<T,K>>) -> Link<T,K> {
+    match h.right.take() {
+        None => h.left.take(),
+        Some(right) => {
+            h.right = delete_max(right);
+            h.update();
+            Some(h)
+        }
+    }
+}
+
+fn delete<T:Ord,K>(link: Link<T,K>, key: &T) -> Link<T,K> {
+    let mut h = link?;
+    match key.cmp(&h.key) {
+        Less => h.left = delete(h.left.take(), key),
+        Greater => h.right = delete(h.right.take(), key),
+        Equal => return join(h.left.take(), h.right.take()),
+    }
+    h.update();
+    Some(h)
+}
+
+/// Joins two treaps, where every key in a is smaller than every key in b.
+/// The root with the largest priority becomes the root of the join
+fn join<T,K>(a: Link<T,K>, b: Link<T,K>) -> Link<T,K> {
+    let (mut a, mut b) = match (a, b) {
+        (None, b) => return b,
+        (a, None) => return a,
+        (Some(a), Some(b)) => (a, b),
+    };
+    if a.priority > b.priority {
+        a.right = join(a.right.take(), Some(b));
+        a.update();
+        Some(a)
+    } else {
+        b.left = join(Some(a), b.left.take());
+        b.update();
+        Some(b)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::searching::search_tree::tests::{check_empty, check_random_operations};
+
+    use super::{priority_of, size, Link, Treap};
+
+    /// Checks the symmetric order of the keys, the heap order of the priorities and the sizes
+    fn check<T:Ord,K>(link: &Link<T,K>, lo: Option<&T>, hi: Option<&T>) {
+        if let Some(node) = link {
+            assert!(lo.is_none_or(|lo| *lo < node.key));
+            assert!(hi.is_none_or(|hi| node.key < *hi));
+            assert!(priority_of(&node.left) <= Some(node.priority));
+            assert!(priority_of(&node.right) <= Some(node.priority));
+            assert_eq!(node.size, 1 + size(&node.left) + size(&node.right));
+            check(&node.left, lo, Some(&node.key));
+            check(&node.right, Some(&node.key), hi);
+        }
+    }
+
+    #[test]
+    fn test_sorted_inserts(){
+        let mut treap: Treap<u32,u32> = Treap::with_seed(7);
+        for i in 0..1023 {
+            treap.put(i, i * 2);
+            check(&treap.root, None, None);
+        }
+        assert_eq!(treap.size(), 1023);
+        assert_eq!(treap.get(&500), Some(&1000));
+        // a plain binary search tree would have height 1022, and the expected height is about 3 log N
+        assert!(treap.height() < 60);
+    }
+
+    #[test]
+    fn test_same_seed_same_shape(){
+        let mut a: Treap<u32,()> = Treap::with_seed(3);
+        let mut b: Treap<u32,()> = Treap::with_seed(3);
+        for i in 0..500 {
+            a.put(i, ());
+            b.put(i, ());
+        }
+        assert_eq!(a.height(), b.height());
+        assert_eq!(a.root.as_ref().map(|n| n.key), b.root.as_ref().map(|n| n.key));
+    }
+
+    #[test]
+    fn test_random_operations(){
+        check_random_operations(Treap::with_seed(48), 48, |treap| check(&treap.root, None, None));
+    }
+
+    #[test]
+    fn test_empty(){
+        let treap = Treap::with_seed(7);
+        assert_eq!(treap.height(), -1);
+        check_empty(treap);
+    }
+}
@@ -58,7 +58,10 @@ impl<T> IndexMinPQ<T>
     }
     
     pub fn min_key(&self) -> Option<T> {
-        if let Some(e) = &self.keys[1] {
+        if self.is_empty() {
+            return None;
+        }
+        if let Some(e) = &self.keys[self.pq[1]] {
             return Some(e.clone());
         }
         None
@@ -66,13 +69,12 @@ impl<T> IndexMinPQ<T>
 
     pub fn delete_min(&mut self) -> usize {
         let min = self.min_index();
-        let mut n = self.n;
+        let n = self.n;
         self.exch(&1, &n);
-        n -= 1;
+        self.n -= 1;
         self.sink(&1);
         self.qp[min] = None;
         self.keys[min] = None;
-        self.n = n;
         min
     }
 
@@ -108,8 +110,10 @@ impl<T> IndexMinPQ<T>
         let tmp = self.n;
         self.exch(&index, &tmp);
         self.n -= 1;
-        self.swim(&index);
-        self.sink(&index);
+        if index <= self.n {
+            self.swim(&index);
+            self.sink(&index);
+        }
         self.keys[*i] = None;
         self.qp[*i] = None;
     }
@@ -125,7 +129,7 @@ impl<T> IndexMinPQ<T>
     fn swim(&mut self, k:&usize){
         let mut tmp = *k;
         while tmp > 1 && self.greater(&(tmp/2), &tmp) {
-            self.exch(k, &(tmp/2));
+            self.exch(&tmp, &(tmp/2));
             tmp = tmp/2;
         }
     }
@@ -147,8 +151,8 @@ impl<T> IndexMinPQ<T>
 
     fn exch(&mut self, i:&usize, j:&usize){
         self.pq.swap(*i, *j);
-        self.qp.get(self.pq[*i]).replace(&Some(*i));
-        self.qp.get(self.pq[*j]).replace(&Some(*j));
+        self.qp[self.pq[*i]] = Some(*i);
+        self.qp[self.pq[*j]] = Some(*j);
     }
 }
 
@@ -164,4 +168,23 @@ mod tests {
         pq.insert(&900, "hej");
         pq.insert(&7, "hej");
     }
+
+    #[test]
+    fn test_delete_min_order(){
+        let mut pq = IndexMinPQ::<u32>::new(10);
+        let keys = [7, 3, 9, 1, 8, 2, 6];
+        for (i, k) in keys.iter().enumerate() {
+            pq.insert(&i, *k);
+        }
+        pq.decrease_key(&2, 0);
+        pq.increase_key(&3, 10);
+        pq.delete(&5);
+        assert_eq!(pq.min_key(), Some(0));
+
+        let mut order = Vec::new();
+        while !pq.is_empty() {
+            order.push(pq.delete_min());
+        }
+        assert_eq!(order, vec![2, 1, 6, 0, 4, 3]);
+    }
 }